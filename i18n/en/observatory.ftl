@@ -2,9 +2,48 @@ app-title = Observatory
 settings = Settings
 about = About
 view = View
+search-processes = Search Processes
+quit = Quit
 git-description = Git commit {$hash} on {$date}
 not-loaded = Not Loaded
+loading = Loading…
+pin-baseline = Pin Baseline
+clear-baseline = Clear Baseline
 not-supported = Not Supported
+cancel = Cancel
+config-error = Failed to save settings: {$error}
+open-url-error = Failed to open {$url}: {$error}
+autostart-error = Failed to update the autostart entry: {$error}
+
+# Command palette
+command-palette = Command Palette
+command-palette-placeholder = Search pages, processes and settings
+command-palette-no-results = No results
+command-palette-page = Page
+command-palette-action = Action
+command-palette-pid = PID {$pid}
+setting-on = On
+setting-off = Off
+
+# Settings
+general-settings = General Settings
+process-settings = Process Settings
+resource-settings = Resource Settings
+run-in-background = Keep Running In Background
+autostart = Start In Background On Login
+scale-by-core = Scale Usage By Core
+hide-end-task-button = Hide End Task Button In Header
+hidden-processes = Hidden Processes
+hidden-pattern-placeholder = Name or pattern, e.g. kworker*
+multicore-view = Show Per-Core Usage Graphs
+graph-palette = Graph Colors
+palette-accent = Accent Color
+palette-high-contrast = High Contrast
+palette-colorblind-safe = Colorblind Safe
+process-name-source = Process Name Source
+name-source-comm = Process Name
+name-source-executable = Executable
+name-source-cmdline = Command Line
 
 
 # === Pages ===
@@ -41,6 +80,8 @@ architecture = Architecture
 processor-stats = Processor Statistics
 frequency = Frequency
 global-utilization = Global Utilization
+core-frequencies = Core Frequencies
+core = Core {$index}
 
 # Memory Page
 memory-info = Memory Information
@@ -51,6 +92,9 @@ dram-type = DRAM Type
 memory-stats = Memory Statistics
 used-memory = Used Memory
 used-swap = Used Swap
+show-treemap = Show Treemap
+show-graph = Show Graph
+treemap-only-mine = Only your processes
 
 # GPU Page
 gpu-info = GPU Information
@@ -71,6 +115,7 @@ gpu-vram-frequency = VRAM Frequency
 gpu-encode = Encoder Utilization
 gpu-decode = Decoder Utilization
 gpu-processes = Number of Processes
+no-gpus = No graphics devices found
 
 # Network Page
 net-info = Network Information
@@ -89,6 +134,7 @@ tx-total = Total Sent
 tx-errors = Send Errors
 is-up = Is Up
 mtu = MTU
+no-networks = No network interfaces found
 
 # Storage Page
 storage-info = Storage Device Information
@@ -107,17 +153,43 @@ disk-temp = Temperature
 disk-serial = Serial Number
 part-label = Partition Label
 smart-status = SMART Status
+no-storage = No storage devices found
+top-disk-processes = Top Processes by Disk Activity
+top-disk-processes-mine = Your Top Processes by Disk Activity
 
 # Processes page
 details = Details
+hide = Hide
+show-hidden = Show Hidden
+hide-kernel-threads = Hide Kernel Threads
+only-mine = Only My Processes
 term = Shutdown
 kill = Force Shutdown
+end-task = End Task
+process-terminated = Shut down process {$pid}
+process-killed = Force shut down process {$pid}
+processes-terminated = Shut down {$count} processes
+processes-killed = Force shut down {$count} processes
+term-error = Failed to shut down: {$error}
+kill-error = Failed to force shut down: {$error}
+processes-selected = {$count} processes selected
 
 proc-info = Process Information
 internal-name = Internal Name
+pid = PID
 cmd-line = Command Line
 exe = Executable
 status = Status
+proc-usage = Resource Usage
+cpu-usage = CPU Usage
+disk-read-rate = Read Rate
+disk-write-rate = Write Rate
+gpu-usage = GPU Usage
+process-ended = This process has ended.
+shown-processes = Showing {$shown} of {$total} processes
+total-cpu = CPU {$usage}%
+total-mem = Memory {$usage}
+total-disk = Disk {$rate}/s
 
 name = Name
 gpu = GPU
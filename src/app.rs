@@ -1,20 +1,31 @@
 // SPDX-License-Identifier: MPL-2.0
-mod page;
+mod activation;
+mod bindings;
+pub mod page;
+pub mod palette;
 
-use crate::config::Config;
+pub use activation::Flags;
+
+use crate::config::{Config, GraphPalette, ProcessNameSource};
 use crate::fl;
+use activation::{Activation, ActivationTarget};
 use cosmic::app::{context_drawer, Core, Task};
 use cosmic::cosmic_config::{self, CosmicConfigEntry};
-use cosmic::iced::{Alignment, Subscription};
+use cosmic::iced::{keyboard, window, Alignment, Size, Subscription};
 use cosmic::prelude::*;
+use cosmic::widget::menu::action::MenuAction as _;
 use cosmic::widget::segmented_button::Entity;
 use cosmic::widget::{self, icon, menu, nav_bar};
 use cosmic::{cosmic_theme, theme, Application};
 use page::Page;
+use palette::PaletteEntry;
 use std::collections::HashMap;
 
 const REPOSITORY: &str = env!("CARGO_PKG_REPOSITORY");
 
+/// Smallest size the main window can be resized to.
+pub const MIN_SIZE: Size = Size::new(360.0, 180.0);
+
 /// The application model stores app-specific state used to describe its interface and
 /// drive its logic.
 pub struct AppModel {
@@ -28,6 +39,19 @@ pub struct AppModel {
     key_binds: HashMap<menu::KeyBind, MenuAction>,
     // Configuration data that persists between application runs.
     config: Config,
+    /// Queue of toasts reporting the results of operations.
+    toasts: widget::Toasts<Message>,
+    /// Labels for the graph palette options in settings.
+    palette_labels: Vec<String>,
+    /// Labels for the process name source options in settings.
+    name_source_labels: Vec<String>,
+    /// Pattern being typed into the hidden processes setting.
+    hidden_pattern: String,
+    /// Whether the autostart entry is installed, read from disk rather than the config so
+    /// the toggle can't drift from the file.
+    autostart: bool,
+    /// Query typed into the command palette, while it is open.
+    palette: Option<String>,
 }
 
 /// Messages emitted by the application and its widgets.
@@ -35,13 +59,38 @@ pub struct AppModel {
 pub enum Message {
     NoOp,
     Error(String),
+    Toast(String),
+    CloseToast(widget::ToastId),
     OpenRepositoryUrl,
     ToggleContextPage(ContextPage),
     UpdateConfig(Config),
     LaunchUrl(String),
+    CloseRequested(window::Id),
+    Quit,
+    Key(keyboard::Modifiers, keyboard::Key),
+    SearchProcesses,
+    TogglePalette,
+    ClosePalette,
+    PaletteInput(String),
+    /// Runs the best match for the command palette's query.
+    SubmitPalette,
+    /// Closes the command palette and sends the chosen entry's message.
+    RunPaletteEntry(Box<Message>),
     // Settings
     SetScaleByCore(bool),
     SetMulticoreView(bool),
+    SetRunInBackground(bool),
+    SetAutostart(bool),
+    SetHideEndTaskButton(bool),
+    SetHideKernelThreads(bool),
+    SetGraphPalette(usize),
+    SetProcessNameSource(usize),
+    SetProcessSort(page::processes::ProcessTableCategory, bool),
+    SetSelectedTab(page::PageId, String),
+    HiddenPatternInput(String),
+    HideProcess(String),
+    HideProcesses(Vec<String>),
+    UnhideProcess(usize),
 
     SystemPage(page::system::SystemMessage),
     CpuPage(page::cpu::CpuMessage),
@@ -50,6 +99,10 @@ pub enum Message {
     NetworkPage(page::network::NetworkMessage),
     StoragePage(page::storage::StorageMessage),
     ProcessPage(page::processes::ProcessMessage),
+    /// Switches to the Processes page with the given PID selected.
+    ShowProcess(u32),
+    /// Switches to the given page.
+    ShowPage(page::PageId),
 }
 
 /// Create a COSMIC application from the app model
@@ -58,7 +111,7 @@ impl Application for AppModel {
     type Executor = cosmic::executor::Default;
 
     /// Data that your application receives to its init method.
-    type Flags = ();
+    type Flags = Flags;
 
     /// Messages which the application and its widgets will emit.
     type Message = Message;
@@ -75,13 +128,13 @@ impl Application for AppModel {
     }
 
     /// Initializes the application with any given flags and startup commands.
-    fn init(core: Core, _flags: Self::Flags) -> (Self, Task<Self::Message>) {
+    fn init(core: Core, flags: Self::Flags) -> (Self, Task<Self::Message>) {
         // Construct the app model with the runtime's core.
         let mut app = AppModel {
             core,
             context_page: ContextPage::default(),
             nav: nav_bar::Model::default(),
-            key_binds: HashMap::new(),
+            key_binds: bindings::key_binds(),
             // Optional configuration file for an application.
             config: cosmic_config::Config::new(Self::APP_ID, Config::VERSION)
                 .map(|context| match Config::get_entry(&context) {
@@ -95,49 +148,84 @@ impl Application for AppModel {
                     }
                 })
                 .unwrap_or_default(),
+            toasts: widget::Toasts::new(Message::CloseToast),
+            palette_labels: vec![
+                fl!("palette-accent"),
+                fl!("palette-high-contrast"),
+                fl!("palette-colorblind-safe"),
+            ],
+            name_source_labels: vec![
+                fl!("name-source-comm"),
+                fl!("name-source-executable"),
+                fl!("name-source-cmdline"),
+            ],
+            hidden_pattern: String::new(),
+            autostart: crate::autostart::is_enabled(),
+            palette: None,
         };
         app.nav
             .insert()
             .text(fl!("system"))
+            .data(page::PageId::System)
             .data(Box::new(page::system::SystemPage::new()) as Box<dyn page::Page>)
             .icon(icon::from_name("preferences-desktop-symbolic"))
             .activate();
         app.nav
             .insert()
             .text(fl!("cpu"))
-            .data(Box::new(page::cpu::CpuPage::new()) as Box<dyn page::Page>)
+            .data(page::PageId::Cpu)
+            .data(Box::new(page::cpu::CpuPage::new(app.config.clone())) as Box<dyn page::Page>)
             .icon(icon::from_name("firmware-manager-symbolic"));
         app.nav
             .insert()
             .text(fl!("memory"))
-            .data(Box::new(page::memory::MemoryPage::new()) as Box<dyn page::Page>)
+            .data(page::PageId::Memory)
+            .data(
+                Box::new(page::memory::MemoryPage::new(app.config.clone())) as Box<dyn page::Page>,
+            )
             .icon(icon::from_name("firmware-manager-symbolic"));
         app.nav
             .insert()
             .text(fl!("gpu"))
-            .data(Box::new(page::gpu::GpuPage::new()) as Box<dyn page::Page>)
+            .data(page::PageId::Gpu)
+            .data(Box::new(page::gpu::GpuPage::new(app.config.clone())) as Box<dyn page::Page>)
             .icon(icon::from_name("firmware-manager-symbolic"));
         app.nav
             .insert()
             .text(fl!("network"))
-            .data(Box::new(page::network::NetworkPage::new()) as Box<dyn page::Page>)
+            .data(page::PageId::Network)
+            .data(
+                Box::new(page::network::NetworkPage::new(app.config.clone()))
+                    as Box<dyn page::Page>,
+            )
             .icon(icon::from_name("network-wireless-symbolic"));
         app.nav
             .insert()
             .text(fl!("storage"))
-            .data(Box::new(page::storage::StoragePage::new()) as Box<dyn page::Page>)
+            .data(page::PageId::Storage)
+            .data(
+                Box::new(page::storage::StoragePage::new(app.config.clone()))
+                    as Box<dyn page::Page>,
+            )
             .icon(icon::from_name("media-floppy-symbolic"));
         app.nav
             .insert()
             .text(fl!("processes"))
+            .data(page::PageId::Processes)
             .data(
                 Box::new(page::processes::ProcessPage::new(app.config.clone()))
                     as Box<dyn page::Page>,
             )
             .icon(icon::from_name("utilities-terminal-symbolic"));
 
-        // Create a startup command that sets the window title.
-        let command = app.update_title();
+        // Create a startup command that sets the window title, or navigates to the requested page.
+        let command = match flags.target() {
+            Some(target) => app.activate_target(target),
+            None => match app.config.last_page.parse::<page::PageId>() {
+                Ok(page) => app.activate_page(page),
+                Err(()) => app.update_title(),
+            },
+        };
 
         (app, command)
     }
@@ -149,9 +237,13 @@ impl Application for AppModel {
             menu::items(
                 &self.key_binds,
                 vec![
+                    menu::Item::Button(fl!("search-processes"), None, MenuAction::SearchProcesses),
+                    menu::Item::Button(fl!("command-palette"), None, MenuAction::CommandPalette),
                     menu::Item::Button(fl!("settings"), None, MenuAction::Settings),
                     menu::Item::Divider,
                     menu::Item::Button(fl!("about"), None, MenuAction::About),
+                    menu::Item::Divider,
+                    menu::Item::Button(fl!("quit"), None, MenuAction::Quit),
                 ],
             ),
         )]);
@@ -159,6 +251,59 @@ impl Application for AppModel {
         vec![menu_bar.into()]
     }
 
+    /// Elements to pack at the end of the header bar.
+    fn header_end(&self) -> Vec<Element<Self::Message>> {
+        if let Some(page) = self.nav.active_data::<Box<dyn Page>>() {
+            page.header_end()
+        } else {
+            vec![]
+        }
+    }
+
+    /// Handles activation from another launch of Observatory, such as
+    /// `observatory --page processes --select-pid 1234`.
+    fn dbus_activation(&mut self, msg: cosmic::dbus_activation::Message) -> Task<Self::Message> {
+        let activation = match msg.msg {
+            cosmic::dbus_activation::Details::ActivateAction { action, .. } => {
+                match action.parse::<Activation>() {
+                    Ok(activation) => Some(activation),
+                    Err(()) => {
+                        tracing::warn!("unknown activation target {action:?}");
+                        None
+                    }
+                }
+            }
+            _ => None,
+        };
+
+        // Another background launch, e.g. from the autostart entry, leaves the window be.
+        if activation == Some(Activation::Background) {
+            return Task::none();
+        }
+
+        let mut tasks = Vec::new();
+        match self.core.main_window_id() {
+            Some(id) => tasks.push(window::gain_focus(id)),
+            None => {
+                // Reopen the main window when running in the background.
+                let (id, open) = window::open(Self::main_window_settings());
+                self.core.set_main_window_id(Some(id));
+                tasks.push(open.discard());
+            }
+        }
+
+        if let Some(Activation::Show(target)) = activation {
+            tasks.push(self.activate_target(target));
+        }
+
+        Task::batch(tasks)
+    }
+
+    /// Keeps the app alive in the background when the main window is closed, if enabled.
+    fn on_close_requested(&self, id: window::Id) -> Option<Self::Message> {
+        Some(Message::CloseRequested(id))
+    }
+
     /// Enables the COSMIC application to create a nav bar with this model.
     fn nav_model(&self) -> Option<&nav_bar::Model> {
         Some(&self.nav)
@@ -197,15 +342,22 @@ impl Application for AppModel {
     /// Application events will be processed through the view. Any messages emitted by
     /// events received by widgets will be passed to the update method.
     fn view(&self) -> Element<Self::Message> {
-        if let Some(page) = self.nav.active_data::<Box<dyn Page>>() {
+        let content = if let Some(page) = self.nav.active_data::<Box<dyn Page>>() {
             page.view()
         } else {
             widget::horizontal_space().apply(Element::from)
-        }
+        };
+
+        widget::toaster(&self.toasts, content)
     }
 
     fn dialog(&self) -> Option<Element<Self::Message>> {
-        if let Some(page) = self.nav.active_data::<Box<dyn Page>>() {
+        if let Some(query) = &self.palette {
+            Some(palette::view(
+                query,
+                palette::search(self.palette_entries(), query),
+            ))
+        } else if let Some(page) = self.nav.active_data::<Box<dyn Page>>() {
             page.dialog()
         } else {
             None
@@ -236,6 +388,10 @@ impl Application for AppModel {
             }
         }
 
+        subscriptions.push(keyboard::on_key_press(|key, modifiers| {
+            Some(Message::Key(modifiers, key))
+        }));
+
         subscriptions.push(
             self.core()
                 .watch_config::<Config>(Self::APP_ID)
@@ -259,7 +415,34 @@ impl Application for AppModel {
         let mut tasks = Vec::new();
         match message.clone() {
             Message::OpenRepositoryUrl => {
-                _ = open::that_detached(REPOSITORY);
+                if let Err(err) = open::that_detached(REPOSITORY) {
+                    tasks.push(self.update(Message::Error(fl!(
+                        "open-url-error",
+                        url = REPOSITORY,
+                        error = err.to_string()
+                    ))));
+                }
+            }
+
+            Message::Error(why) => {
+                tracing::error!("{why}");
+                tasks.push(
+                    self.toasts
+                        .push(widget::toaster::Toast::new(why))
+                        .map(cosmic::Action::App),
+                );
+            }
+
+            Message::Toast(text) => {
+                tasks.push(
+                    self.toasts
+                        .push(widget::toaster::Toast::new(text))
+                        .map(cosmic::Action::App),
+                );
+            }
+
+            Message::CloseToast(id) => {
+                self.toasts.remove(id);
             }
 
             Message::ToggleContextPage(context_page) => {
@@ -280,26 +463,197 @@ impl Application for AppModel {
             Message::LaunchUrl(url) => match open::that_detached(&url) {
                 Ok(()) => {}
                 Err(err) => {
-                    tracing::error!("failed to open {url:?}: {err}");
+                    tasks.push(self.update(Message::Error(fl!(
+                        "open-url-error",
+                        url = url,
+                        error = err.to_string()
+                    ))));
                 }
             },
 
+            Message::CloseRequested(id) => {
+                if self.core.main_window_id() == Some(id) {
+                    if self.config.run_in_background {
+                        // Keep collecting data with no window open until the user quits.
+                        self.core.set_main_window_id(None);
+                        tasks.push(window::close(id));
+                    } else {
+                        tasks.push(cosmic::iced::exit());
+                    }
+                }
+            }
+
+            Message::ShowProcess(pid) => {
+                tasks.push(self.activate_target(ActivationTarget {
+                    page: page::PageId::Processes,
+                    pid: Some(pid),
+                }));
+            }
+
+            Message::ShowPage(page) => {
+                tasks.push(self.activate_page(page));
+            }
+
+            Message::Quit => {
+                tasks.push(cosmic::iced::exit());
+            }
+
+            Message::Key(_, keyboard::Key::Named(keyboard::key::Named::Escape))
+                if self.palette.is_some() =>
+            {
+                self.palette = None;
+            }
+
+            Message::Key(modifiers, key) => {
+                for (key_bind, action) in &self.key_binds {
+                    if key_bind.matches(modifiers, &key) {
+                        return self.update(action.message());
+                    }
+                }
+            }
+
+            Message::SearchProcesses => {
+                // The processes page focuses its search input when it receives this message.
+                tasks.push(self.activate_target(ActivationTarget {
+                    page: page::PageId::Processes,
+                    pid: None,
+                }));
+            }
+
+            Message::TogglePalette => {
+                if self.palette.take().is_none() {
+                    self.palette = Some(String::new());
+                    tasks.push(widget::text_input::focus(palette::INPUT_ID.clone()));
+                }
+            }
+
+            Message::ClosePalette => {
+                self.palette = None;
+            }
+
+            Message::PaletteInput(query) => {
+                if self.palette.is_some() {
+                    self.palette = Some(query);
+                }
+            }
+
+            Message::SubmitPalette => {
+                let best = self.palette.as_deref().and_then(|query| {
+                    palette::search(self.palette_entries(), query)
+                        .into_iter()
+                        .next()
+                });
+                if let Some(entry) = best {
+                    tasks.push(self.update(Message::RunPaletteEntry(Box::new(entry.message))));
+                }
+            }
+
+            Message::RunPaletteEntry(message) => {
+                self.palette = None;
+                tasks.push(self.update(*message));
+            }
+
             Message::SetScaleByCore(state) => {
-                self.config
-                    .set_scale_by_core(
-                        &cosmic_config::Config::new(Self::APP_ID, Config::VERSION).unwrap(),
-                        state,
-                    )
-                    .unwrap();
+                tasks.push(
+                    self.save_config(|config, context| config.set_scale_by_core(context, state)),
+                );
             }
 
             Message::SetMulticoreView(state) => {
-                self.config
-                    .set_multicore_view(
-                        &cosmic_config::Config::new(Self::APP_ID, Config::VERSION).unwrap(),
-                        state,
-                    )
-                    .unwrap();
+                tasks.push(
+                    self.save_config(|config, context| config.set_multicore_view(context, state)),
+                );
+            }
+
+            Message::SetRunInBackground(state) => {
+                tasks.push(
+                    self.save_config(|config, context| {
+                        config.set_run_in_background(context, state)
+                    }),
+                );
+            }
+
+            Message::SetAutostart(state) => match crate::autostart::set_enabled(state) {
+                Ok(()) => self.autostart = state,
+                Err(err) => {
+                    tasks.push(self.update(Message::Error(fl!(
+                        "autostart-error",
+                        error = err.to_string()
+                    ))));
+                }
+            },
+
+            Message::SetHideEndTaskButton(state) => {
+                tasks.push(self.save_config(|config, context| {
+                    config.set_hide_end_task_button(context, state)
+                }));
+            }
+
+            Message::SetHideKernelThreads(state) => {
+                tasks.push(
+                    self.save_config(|config, context| {
+                        config.set_hide_kernel_threads(context, state)
+                    }),
+                );
+            }
+
+            Message::SetGraphPalette(index) => {
+                tasks.push(self.save_config(|config, context| {
+                    config.set_graph_palette(context, GraphPalette::ALL[index])
+                }));
+            }
+
+            Message::SetProcessNameSource(index) => {
+                tasks.push(self.save_config(|config, context| {
+                    config.set_process_name_source(context, ProcessNameSource::ALL[index])
+                }));
+            }
+
+            Message::SetProcessSort(category, descending) => {
+                tasks.push(self.save_config(|config, context| {
+                    config.set_process_sort(context, Some((category, descending)))
+                }));
+            }
+
+            Message::SetSelectedTab(page, name) => {
+                let mut selected_tabs = self.config.selected_tabs.clone();
+                selected_tabs.insert(page.to_string(), name);
+                tasks.push(self.save_config(|config, context| {
+                    config.set_selected_tabs(context, selected_tabs)
+                }));
+            }
+
+            Message::HiddenPatternInput(pattern) => {
+                self.hidden_pattern = pattern;
+            }
+
+            Message::HideProcess(pattern) => {
+                let pattern = pattern.trim();
+                if !pattern.is_empty() && !self.config.hidden_processes.iter().any(|p| p == pattern)
+                {
+                    let mut hidden = self.config.hidden_processes.clone();
+                    hidden.push(pattern.to_owned());
+                    tasks.push(self.save_config(|config, context| {
+                        config.set_hidden_processes(context, hidden)
+                    }));
+                }
+                self.hidden_pattern.clear();
+            }
+
+            Message::HideProcesses(names) => {
+                for name in names {
+                    tasks.push(self.update(Message::HideProcess(name)));
+                }
+            }
+
+            Message::UnhideProcess(index) => {
+                let mut hidden = self.config.hidden_processes.clone();
+                if index < hidden.len() {
+                    hidden.remove(index);
+                    tasks.push(self.save_config(|config, context| {
+                        config.set_hidden_processes(context, hidden)
+                    }));
+                }
             }
 
             _ => {}
@@ -318,7 +672,15 @@ impl Application for AppModel {
         // Activate the page in the model.
         self.nav.activate(id);
 
-        self.update_title()
+        let mut tasks = Vec::new();
+        if let Some(page) = self.nav.data::<page::PageId>(id).copied() {
+            tasks.push(
+                self.save_config(|config, context| config.set_last_page(context, page.to_string())),
+            );
+        }
+
+        tasks.push(self.update_title());
+        Task::batch(tasks)
     }
 }
 
@@ -360,24 +722,215 @@ impl AppModel {
     pub fn settings(&self) -> Element<Message> {
         widget::settings::view_column(vec![
             widget::settings::section()
-                .title("Process Settings")
+                .title(fl!("general-settings"))
+                .add(widget::settings::item(
+                    fl!("run-in-background"),
+                    widget::toggler(self.config.run_in_background)
+                        .on_toggle(Message::SetRunInBackground),
+                ))
+                .add(widget::settings::item(
+                    fl!("autostart"),
+                    widget::toggler(self.autostart).on_toggle(Message::SetAutostart),
+                ))
+                .apply(Element::from),
+            widget::settings::section()
+                .title(fl!("process-settings"))
                 .add(widget::settings::item(
-                    "Scale Usage By Core",
+                    fl!("scale-by-core"),
                     widget::toggler(self.config.scale_by_core).on_toggle(Message::SetScaleByCore),
                 ))
+                .add(widget::settings::item(
+                    fl!("process-name-source"),
+                    widget::dropdown(
+                        &self.name_source_labels,
+                        ProcessNameSource::ALL
+                            .iter()
+                            .position(|source| *source == self.config.process_name_source),
+                        Message::SetProcessNameSource,
+                    ),
+                ))
+                .add(widget::settings::item(
+                    fl!("hide-end-task-button"),
+                    widget::toggler(self.config.hide_end_task_button)
+                        .on_toggle(Message::SetHideEndTaskButton),
+                ))
+                .apply(Element::from),
+            self.config
+                .hidden_processes
+                .iter()
+                .enumerate()
+                .fold(
+                    widget::settings::section().title(fl!("hidden-processes")),
+                    |section, (index, pattern)| {
+                        section.add(widget::settings::item(
+                            pattern.clone(),
+                            widget::button::icon(icon::from_name("edit-delete-symbolic"))
+                                .on_press(Message::UnhideProcess(index)),
+                        ))
+                    },
+                )
+                .add(
+                    widget::text_input(fl!("hidden-pattern-placeholder"), &self.hidden_pattern)
+                        .on_input(Message::HiddenPatternInput)
+                        .on_submit(Message::HideProcess),
+                )
                 .apply(Element::from),
             widget::settings::section()
-                .title("Resource Settings")
+                .title(fl!("resource-settings"))
                 .add(widget::settings::item(
-                    "Show Per-Core Usage Graphs",
+                    fl!("multicore-view"),
                     widget::toggler(self.config.multicore_view)
                         .on_toggle(Message::SetMulticoreView),
                 ))
+                .add(widget::settings::item(
+                    fl!("graph-palette"),
+                    widget::dropdown(
+                        &self.palette_labels,
+                        GraphPalette::ALL
+                            .iter()
+                            .position(|palette| *palette == self.config.graph_palette),
+                        Message::SetGraphPalette,
+                    ),
+                ))
                 .apply(Element::from),
         ])
         .apply(Element::from)
     }
 
+    /// Everything the command palette can search: the nav pages, settings actions and the
+    /// entries each page provides.
+    fn palette_entries(&self) -> Vec<PaletteEntry> {
+        let mut entries = Vec::new();
+
+        for entity in self.nav.iter() {
+            if let (Some(page), Some(label)) =
+                (self.nav.data::<page::PageId>(entity), self.nav.text(entity))
+            {
+                entries.push(PaletteEntry {
+                    label: label.to_owned(),
+                    detail: fl!("command-palette-page"),
+                    message: Message::ShowPage(*page),
+                });
+            }
+        }
+
+        for (label, action) in [
+            (fl!("search-processes"), MenuAction::SearchProcesses),
+            (fl!("settings"), MenuAction::Settings),
+            (fl!("about"), MenuAction::About),
+            (fl!("quit"), MenuAction::Quit),
+        ] {
+            entries.push(PaletteEntry {
+                label,
+                detail: fl!("command-palette-action"),
+                message: action.message(),
+            });
+        }
+
+        let toggles: [(String, bool, fn(bool) -> Message); 6] = [
+            (
+                fl!("run-in-background"),
+                self.config.run_in_background,
+                Message::SetRunInBackground,
+            ),
+            (fl!("autostart"), self.autostart, Message::SetAutostart),
+            (
+                fl!("scale-by-core"),
+                self.config.scale_by_core,
+                Message::SetScaleByCore,
+            ),
+            (
+                fl!("hide-end-task-button"),
+                self.config.hide_end_task_button,
+                Message::SetHideEndTaskButton,
+            ),
+            (
+                fl!("hide-kernel-threads"),
+                self.config.hide_kernel_threads,
+                Message::SetHideKernelThreads,
+            ),
+            (
+                fl!("multicore-view"),
+                self.config.multicore_view,
+                Message::SetMulticoreView,
+            ),
+        ];
+        for (label, state, message) in toggles {
+            entries.push(PaletteEntry {
+                label,
+                detail: if state {
+                    fl!("setting-on")
+                } else {
+                    fl!("setting-off")
+                },
+                message: message(!state),
+            });
+        }
+
+        for entity in self.nav.iter() {
+            if let Some(page) = self.nav.data::<Box<dyn Page>>(entity) {
+                entries.extend(page.palette_entries());
+            }
+        }
+
+        entries
+    }
+
+    /// Writes a config change, reporting a failure as an error toast rather than
+    /// crashing on a routine click.
+    fn save_config(
+        &mut self,
+        set: impl FnOnce(&mut Config, &cosmic_config::Config) -> Result<bool, cosmic_config::Error>,
+    ) -> Task<Message> {
+        match cosmic_config::Config::new(Self::APP_ID, Config::VERSION)
+            .and_then(|context| set(&mut self.config, &context))
+        {
+            Ok(_) => Task::none(),
+            Err(why) => self.update(Message::Error(fl!("config-error", error = why.to_string()))),
+        }
+    }
+
+    /// Navigates to the page of an activation target and selects its process, if any.
+    pub fn activate_target(&mut self, target: ActivationTarget) -> Task<Message> {
+        let mut tasks = vec![self.activate_page(target.page)];
+        if let Some(pid) = target.pid {
+            tasks.push(Task::done(cosmic::Action::App(Message::ProcessPage(
+                page::processes::ProcessMessage::SelectPid(pid),
+            ))));
+        }
+
+        Task::batch(tasks)
+    }
+
+    /// Switches the nav bar to the given page.
+    pub fn activate_page(&mut self, page: page::PageId) -> Task<Message> {
+        let entity = self
+            .nav
+            .iter()
+            .find(|&entity| self.nav.data::<page::PageId>(entity) == Some(&page));
+        if let Some(entity) = entity {
+            self.nav.activate(entity);
+        }
+
+        self.update_title()
+    }
+
+    /// Window settings matching the main window libcosmic opens at startup, so a window
+    /// reopened from the background keeps the size limits and client-side decorations.
+    fn main_window_settings() -> window::Settings {
+        let mut settings = window::Settings {
+            size: Size::new(1024.0, 768.0),
+            min_size: Some(MIN_SIZE),
+            resizable: true,
+            decorations: false,
+            transparent: true,
+            exit_on_close_request: false,
+            ..Default::default()
+        };
+        settings.platform_specific.application_id = Self::APP_ID.to_owned();
+        settings
+    }
+
     /// Updates the header and window titles.
     pub fn update_title(&mut self) -> Task<Message> {
         let mut window_title = fl!("app-title");
@@ -406,8 +959,11 @@ pub enum ContextPage {
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum MenuAction {
+    SearchProcesses,
+    CommandPalette,
     Settings,
     About,
+    Quit,
 }
 
 impl menu::action::MenuAction for MenuAction {
@@ -415,8 +971,11 @@ impl menu::action::MenuAction for MenuAction {
 
     fn message(&self) -> Self::Message {
         match self {
+            MenuAction::SearchProcesses => Message::SearchProcesses,
+            MenuAction::CommandPalette => Message::TogglePalette,
             MenuAction::Settings => Message::ToggleContextPage(ContextPage::Settings),
             MenuAction::About => Message::ToggleContextPage(ContextPage::About),
+            MenuAction::Quit => Message::Quit,
         }
     }
 }
@@ -0,0 +1,223 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! Command line flags and single-instance activation targets.
+
+use std::{fmt, str::FromStr};
+
+use super::page::PageId;
+
+/// Data that the application receives when launched or activated.
+#[derive(Clone, Debug, Default)]
+pub struct Flags {
+    /// What the launch asked for, if anything beyond opening the window.
+    pub activation: Option<Activation>,
+}
+
+impl Flags {
+    /// Parses `--background`, `--page <page>` and `--select-pid <pid>` from the command
+    /// line.
+    pub fn from_args(args: impl Iterator<Item = String>) -> Self {
+        let mut background = false;
+        let mut page = None;
+        let mut pid = None;
+
+        let mut args = args.skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--background" => background = true,
+                "--page" => page = args.next().and_then(|page| page.parse().ok()),
+                "--select-pid" => pid = args.next().and_then(|pid| pid.parse().ok()),
+                _ => {}
+            }
+        }
+
+        // Selecting a process implies showing the processes page.
+        let page = page.or(pid.map(|_| PageId::Processes));
+
+        let activation = if background {
+            Some(Activation::Background)
+        } else {
+            page.map(|page| Activation::Show(ActivationTarget { page, pid }))
+        };
+
+        Self { activation }
+    }
+
+    /// Page, and optionally a process, to show once the window is up.
+    pub fn target(&self) -> Option<ActivationTarget> {
+        match self.activation {
+            Some(Activation::Show(target)) => Some(target),
+            _ => None,
+        }
+    }
+
+    /// Whether to start collecting data without opening a window, as the autostart
+    /// entry does.
+    pub fn background(&self) -> bool {
+        self.activation == Some(Activation::Background)
+    }
+}
+
+impl cosmic::app::CosmicFlags for Flags {
+    type SubCommand = Activation;
+    type Args = Vec<String>;
+
+    /// Forwards the activation to an already running instance as its activation action.
+    fn action(&self) -> Option<&Self::SubCommand> {
+        self.activation.as_ref()
+    }
+}
+
+/// What a launch asks of the application, passed as the D-Bus activation action when an
+/// instance is already running.
+///
+/// Serialized as `background` or as its target.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Activation {
+    /// Show the window on a page.
+    Show(ActivationTarget),
+    /// Keep running without touching the window.
+    Background,
+}
+
+impl fmt::Display for Activation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Show(target) => target.fmt(f),
+            Self::Background => f.write_str("background"),
+        }
+    }
+}
+
+impl FromStr for Activation {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "background" => Ok(Self::Background),
+            _ => s.parse().map(Self::Show),
+        }
+    }
+}
+
+/// A page to navigate to, with a process to select on the processes page.
+///
+/// Serialized as `page` or `page:pid` when passed over D-Bus activation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ActivationTarget {
+    pub page: PageId,
+    pub pid: Option<u32>,
+}
+
+impl fmt::Display for ActivationTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.pid {
+            Some(pid) => write!(f, "{}:{pid}", self.page),
+            None => write!(f, "{}", self.page),
+        }
+    }
+}
+
+impl FromStr for ActivationTarget {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (page, pid) = match s.split_once(':') {
+            Some((page, pid)) => (page, Some(pid.parse().map_err(|_| ())?)),
+            None => (s, None),
+        };
+
+        Ok(Self {
+            page: page.parse()?,
+            pid,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flags(args: &[&str]) -> Flags {
+        Flags::from_args(
+            std::iter::once("observatory")
+                .chain(args.iter().copied())
+                .map(str::to_owned),
+        )
+    }
+
+    #[test]
+    fn no_arguments_has_no_target() {
+        assert_eq!(flags(&[]).target(), None);
+        assert!(!flags(&[]).background());
+    }
+
+    #[test]
+    fn background_overrides_target() {
+        let flags = flags(&["--background", "--page", "cpu"]);
+        assert!(flags.background());
+        assert_eq!(flags.target(), None);
+        assert_eq!(flags.activation, Some(Activation::Background));
+    }
+
+    #[test]
+    fn page_argument_selects_page() {
+        assert_eq!(
+            flags(&["--page", "memory"]).target(),
+            Some(ActivationTarget {
+                page: PageId::Memory,
+                pid: None,
+            })
+        );
+        assert_eq!(flags(&["--page", "bogus"]).target(), None);
+    }
+
+    #[test]
+    fn pid_implies_processes_page() {
+        assert_eq!(
+            flags(&["--select-pid", "1234"]).target(),
+            Some(ActivationTarget {
+                page: PageId::Processes,
+                pid: Some(1234),
+            })
+        );
+        assert_eq!(flags(&["--select-pid", "abc"]).target(), None);
+    }
+
+    #[test]
+    fn target_round_trips_through_string() {
+        for target in [
+            ActivationTarget {
+                page: PageId::Cpu,
+                pid: None,
+            },
+            ActivationTarget {
+                page: PageId::Processes,
+                pid: Some(42),
+            },
+        ] {
+            assert_eq!(target.to_string().parse(), Ok(target));
+        }
+    }
+
+    #[test]
+    fn activation_round_trips_through_string() {
+        for activation in [
+            Activation::Background,
+            Activation::Show(ActivationTarget {
+                page: PageId::Processes,
+                pid: Some(42),
+            }),
+        ] {
+            assert_eq!(activation.to_string().parse(), Ok(activation));
+        }
+        assert_eq!("nowhere".parse::<Activation>(), Err(()));
+    }
+
+    #[test]
+    fn invalid_targets_are_rejected() {
+        assert_eq!("".parse::<ActivationTarget>(), Err(()));
+        assert_eq!("network:".parse::<ActivationTarget>(), Err(()));
+        assert_eq!("nowhere:1".parse::<ActivationTarget>(), Err(()));
+    }
+}
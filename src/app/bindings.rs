@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MPL-2.0
+
+use super::MenuAction;
+use cosmic::iced::keyboard::Key;
+use cosmic::widget::menu::key_bind::{KeyBind, Modifier};
+use std::collections::HashMap;
+
+/// Keyboard shortcuts for menu actions, shown in the menu bar and handled in `update`.
+pub fn key_binds() -> HashMap<KeyBind, MenuAction> {
+    let mut key_binds = HashMap::new();
+
+    key_binds.insert(
+        KeyBind {
+            modifiers: vec![Modifier::Ctrl],
+            key: Key::Character("f".into()),
+        },
+        MenuAction::SearchProcesses,
+    );
+
+    key_binds.insert(
+        KeyBind {
+            modifiers: vec![Modifier::Ctrl],
+            key: Key::Character("k".into()),
+        },
+        MenuAction::CommandPalette,
+    );
+
+    key_binds
+}
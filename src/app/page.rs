@@ -3,12 +3,143 @@ pub mod gpu;
 pub mod memory;
 pub mod network;
 pub mod processes;
+#[cfg(test)]
+mod replay;
 pub mod storage;
 pub mod system;
 
 use super::Message;
+use crate::{config::GraphPalette, fl, widget::graph::LineGraph};
 use cosmic::app::Task;
+use cosmic::iced::Length;
 use cosmic::prelude::*;
+use cosmic::widget;
+use std::{collections::VecDeque, fmt, str::FromStr};
+
+/// Identifies a page in the nav bar independently of its localized title.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PageId {
+    System,
+    Cpu,
+    Memory,
+    Gpu,
+    Network,
+    Storage,
+    Processes,
+}
+
+impl PageId {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::System => "system",
+            Self::Cpu => "cpu",
+            Self::Memory => "memory",
+            Self::Gpu => "gpu",
+            Self::Network => "network",
+            Self::Storage => "storage",
+            Self::Processes => "processes",
+        }
+    }
+}
+
+impl fmt::Display for PageId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for PageId {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "system" => Ok(Self::System),
+            "cpu" => Ok(Self::Cpu),
+            "memory" => Ok(Self::Memory),
+            "gpu" => Ok(Self::Gpu),
+            "network" => Ok(Self::Network),
+            "storage" => Ok(Self::Storage),
+            "processes" => Ok(Self::Processes),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Placeholder shown until a page receives its first snapshot from monitord.
+pub fn loading<'a>() -> Element<'a, Message> {
+    widget::text::body(fl!("loading"))
+        .apply(widget::container)
+        .center(Length::Fill)
+        .apply(Element::from)
+}
+
+/// Shown in place of a page's content when monitord reports no devices for it.
+pub fn empty<'a>(message: String) -> Element<'a, Message> {
+    widget::text::body(message)
+        .apply(widget::container)
+        .center(Length::Fill)
+        .apply(Element::from)
+}
+
+/// Pins the current history as the comparison baseline, or clears a pinned one.
+pub fn toggle_baseline(baseline: &mut Option<Vec<f32>>, history: &VecDeque<f32>) {
+    *baseline = match baseline {
+        Some(_) => None,
+        None => Some(history.iter().cloned().collect()),
+    };
+}
+
+/// Line graph of a history in absolute units such as bytes per second. The baseline is
+/// scaled with the live history so both share an axis.
+pub fn scaled_line_graph(
+    history: &VecDeque<f32>,
+    baseline: Option<&[f32]>,
+    palette: GraphPalette,
+) -> LineGraph {
+    let max = history
+        .iter()
+        .chain(baseline.into_iter().flatten())
+        .cloned()
+        .fold(1.0, f32::max);
+
+    LineGraph {
+        points: history.iter().map(|val| val / max).collect(),
+        baseline: baseline.map(|baseline| baseline.iter().map(|val| val / max).collect()),
+        palette,
+    }
+}
+
+/// Footer with a button to pin or clear the comparison baseline of a page's graph,
+/// preceded by any page-specific buttons. The baseline button is left out without a message.
+pub fn baseline_footer<'a>(
+    pinned: bool,
+    on_press: Option<Message>,
+    buttons: Vec<Element<'a, Message>>,
+) -> Element<'a, Message> {
+    let theme = cosmic::theme::active();
+    let cosmic = theme.cosmic();
+    buttons
+        .into_iter()
+        .fold(
+            widget::row()
+                .push(widget::horizontal_space())
+                .spacing(cosmic.space_xxxs())
+                .padding([cosmic.space_xxxs(), cosmic.space_xxs()]),
+            |row, button| row.push(button),
+        )
+        .push_maybe(on_press.map(|on_press| {
+            if pinned {
+                fl!("clear-baseline")
+            } else {
+                fl!("pin-baseline")
+            }
+            .apply(widget::button::text)
+            .on_press(on_press)
+        }))
+        .apply(widget::layer_container)
+        .layer(cosmic::cosmic_theme::Layer::Primary)
+        .apply(Element::from)
+}
 
 pub trait Page {
     fn update(&mut self, _: Message) -> Task<Message> {
@@ -16,7 +147,11 @@ pub trait Page {
     }
 
     fn view(&self) -> Element<Message> {
-        cosmic::widget::horizontal_space().apply(Element::from)
+        widget::horizontal_space().apply(Element::from)
+    }
+
+    fn header_end(&self) -> Vec<Element<Message>> {
+        vec![]
     }
 
     fn footer(&self) -> Option<Element<Message>> {
@@ -34,4 +169,9 @@ pub trait Page {
     fn subscription(&self) -> Vec<cosmic::iced::Subscription<Message>> {
         vec![]
     }
+
+    /// Entries this page offers to the command palette.
+    fn palette_entries(&self) -> Vec<super::palette::PaletteEntry> {
+        vec![]
+    }
 }
@@ -2,6 +2,7 @@ use std::collections::VecDeque;
 
 use crate::{
     app::Message,
+    config::Config,
     fl,
     helpers::{format_number, get_bytes},
 };
@@ -20,18 +21,24 @@ use monitord_protocols::{
 #[derive(Debug, Clone)]
 pub enum CpuMessage {
     Snapshot(CpuInfo),
+    ToggleBaseline,
 }
 
 pub struct CpuPage {
     cpu_usage_history: VecDeque<f32>,
     cpu_info: Option<CpuInfo>,
+    baseline: Option<Vec<f32>>,
+    // Configuration data that persists between application runs.
+    config: Config,
 }
 
 impl CpuPage {
-    pub fn new() -> Self {
+    pub fn new(config: Config) -> Self {
         Self {
             cpu_usage_history: VecDeque::from(vec![0.0; 30]),
             cpu_info: None,
+            baseline: None,
+            config,
         }
     }
 }
@@ -40,12 +47,16 @@ impl super::Page for CpuPage {
     fn update(&mut self, msg: Message) -> cosmic::app::Task<Message> {
         let tasks = Vec::new();
         match msg {
+            Message::UpdateConfig(config) => self.config = config,
             Message::CpuPage(CpuMessage::Snapshot(snapshot)) => {
                 self.cpu_usage_history
                     .push_back(snapshot.global_utilization_percent as f32 / 100.0);
                 self.cpu_usage_history.pop_front();
                 self.cpu_info = Some(snapshot);
             }
+            Message::CpuPage(CpuMessage::ToggleBaseline) => {
+                super::toggle_baseline(&mut self.baseline, &self.cpu_usage_history);
+            }
             _ => {}
         }
 
@@ -61,103 +72,135 @@ impl super::Page for CpuPage {
                 .push(
                     widget::canvas(crate::widget::graph::LineGraph {
                         points: self.cpu_usage_history.iter().cloned().collect(),
+                        baseline: self.baseline.clone(),
+                        palette: self.config.graph_palette,
                     })
                     .width(iced::Length::Fill)
                     .height(iced::Length::Fill),
                 )
-                .push(widget::settings::view_column(vec![
-                    widget::settings::section()
-                        .title(fl!("processor-info"))
-                        .add(widget::settings::item(
-                            fl!("model-name"),
-                            cpu_info.model_name.clone().apply(widget::text::body),
-                        ))
-                        .add(widget::settings::item(
-                            fl!("physical-cores"),
-                            cpu_info
-                                .physical_cores
-                                .to_string()
-                                .apply(widget::text::body),
-                        ))
-                        .add(widget::settings::item(
-                            fl!("logical-cores"),
-                            cpu_info
-                                .logical_cores
-                                .to_string()
-                                .clone()
-                                .apply(widget::text::body),
-                        ))
-                        .add(widget::settings::item(
-                            fl!("l1-instruction-cache"),
-                            cpu_info
-                                .cache_info
-                                .map(|ci| ci.l1_instruction_kb as u64 * 1024)
-                                .unwrap_or_default()
-                                .apply(get_bytes)
-                                .apply(widget::text::body),
-                        ))
-                        .add(widget::settings::item(
-                            fl!("l1-data-cache"),
-                            cpu_info
-                                .cache_info
-                                .map(|ci| ci.l1_data_kb as u64 * 1024)
-                                .unwrap_or_default()
-                                .apply(get_bytes)
-                                .apply(widget::text::body),
-                        ))
-                        .add(widget::settings::item(
-                            fl!("l2-cache"),
-                            cpu_info
-                                .cache_info
-                                .map(|ci| ci.l2_kb as u64 * 1024)
-                                .unwrap_or_default()
-                                .apply(get_bytes)
-                                .apply(widget::text::body),
-                        ))
-                        .add(widget::settings::item(
-                            fl!("l3-cache"),
-                            cpu_info
-                                .cache_info
-                                .map(|ci| ci.l3_kb as u64 * 1024)
-                                .unwrap_or_default()
-                                .apply(get_bytes)
-                                .apply(widget::text::body),
-                        ))
-                        .add(widget::settings::item(
-                            fl!("architecture"),
-                            cpu_info.architecture.clone().apply(widget::text::body),
-                        ))
-                        .apply(Element::from),
-                    widget::settings::section()
-                        .title(fl!("processor-stats"))
-                        .add(widget::settings::item(
-                            fl!("frequency"),
-                            cpu_info
-                                .core_info
-                                .iter()
-                                .map(|core| core.frequency_mhz)
-                                .max_by(|a, b| a.partial_cmp(b).unwrap())
-                                .unwrap_or_default()
-                                .apply(|freq| format!("{} GHz", format_number(freq / 1000.0)))
-                                .apply(widget::text::body),
-                        ))
-                        .add(widget::settings::item(
-                            fl!("global-utilization"),
-                            cpu_info
-                                .global_utilization_percent
-                                .apply(|util| format!("{}%", format_number(util)))
-                                .to_string()
-                                .apply(widget::text::body),
-                        ))
-                        .apply(widget::scrollable)
-                        .apply(Element::from),
-                ]))
+                .push(
+                    widget::settings::view_column(vec![
+                        widget::settings::section()
+                            .title(fl!("processor-info"))
+                            .add(widget::settings::item(
+                                fl!("model-name"),
+                                cpu_info.model_name.clone().apply(widget::text::body),
+                            ))
+                            .add(widget::settings::item(
+                                fl!("physical-cores"),
+                                cpu_info
+                                    .physical_cores
+                                    .to_string()
+                                    .apply(widget::text::body),
+                            ))
+                            .add(widget::settings::item(
+                                fl!("logical-cores"),
+                                cpu_info
+                                    .logical_cores
+                                    .to_string()
+                                    .clone()
+                                    .apply(widget::text::body),
+                            ))
+                            .add(widget::settings::item(
+                                fl!("l1-instruction-cache"),
+                                cpu_info
+                                    .cache_info
+                                    .map(|ci| ci.l1_instruction_kb as u64 * 1024)
+                                    .unwrap_or_default()
+                                    .apply(get_bytes)
+                                    .apply(widget::text::body),
+                            ))
+                            .add(widget::settings::item(
+                                fl!("l1-data-cache"),
+                                cpu_info
+                                    .cache_info
+                                    .map(|ci| ci.l1_data_kb as u64 * 1024)
+                                    .unwrap_or_default()
+                                    .apply(get_bytes)
+                                    .apply(widget::text::body),
+                            ))
+                            .add(widget::settings::item(
+                                fl!("l2-cache"),
+                                cpu_info
+                                    .cache_info
+                                    .map(|ci| ci.l2_kb as u64 * 1024)
+                                    .unwrap_or_default()
+                                    .apply(get_bytes)
+                                    .apply(widget::text::body),
+                            ))
+                            .add(widget::settings::item(
+                                fl!("l3-cache"),
+                                cpu_info
+                                    .cache_info
+                                    .map(|ci| ci.l3_kb as u64 * 1024)
+                                    .unwrap_or_default()
+                                    .apply(get_bytes)
+                                    .apply(widget::text::body),
+                            ))
+                            .add(widget::settings::item(
+                                fl!("architecture"),
+                                cpu_info.architecture.clone().apply(widget::text::body),
+                            ))
+                            .apply(Element::from),
+                        widget::settings::section()
+                            .title(fl!("processor-stats"))
+                            .add(widget::settings::item(
+                                fl!("frequency"),
+                                cpu_info
+                                    .core_info
+                                    .iter()
+                                    .map(|core| core.frequency_mhz)
+                                    .max_by(|a, b| a.partial_cmp(b).unwrap())
+                                    .unwrap_or_default()
+                                    .apply(|freq| format!("{} GHz", format_number(freq / 1000.0)))
+                                    .apply(widget::text::body),
+                            ))
+                            .add(widget::settings::item(
+                                fl!("global-utilization"),
+                                cpu_info
+                                    .global_utilization_percent
+                                    .apply(|util| format!("{}%", format_number(util)))
+                                    .to_string()
+                                    .apply(widget::text::body),
+                            ))
+                            .apply(Element::from),
+                        cpu_info
+                            .core_info
+                            .iter()
+                            .enumerate()
+                            .fold(
+                                widget::settings::section().title(fl!("core-frequencies")),
+                                |section, (index, core)| {
+                                    section.add(widget::settings::item(
+                                        fl!("core", index = index),
+                                        format!(
+                                            "{} GHz",
+                                            format_number(core.frequency_mhz / 1000.0)
+                                        )
+                                        .apply(widget::text::body),
+                                    ))
+                                },
+                            )
+                            .apply(Element::from),
+                    ])
+                    .apply(widget::scrollable),
+                )
                 .apply(Element::from)
         } else {
-            widget::horizontal_space().apply(Element::from)
+            super::loading()
         }
     }
 
+    fn footer(&self) -> Option<Element<Message>> {
+        self.cpu_info.as_ref().map(|_| {
+            super::baseline_footer(
+                self.baseline.is_some(),
+                Some(Message::CpuPage(CpuMessage::ToggleBaseline)),
+                vec![],
+            )
+        })
+    }
+
     fn subscription(&self) -> Vec<Subscription<Message>> {
         vec![Subscription::run(|| {
             stream::channel(1, |mut sender| async move {
@@ -183,3 +226,29 @@ impl super::Page for CpuPage {
         })]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::page::{replay, Page};
+
+    #[test]
+    fn replayed_history_keeps_its_length() {
+        let mut page = CpuPage::new(Config::default());
+        replay::replay(&mut page, replay::load(replay::DESKTOP));
+
+        assert_eq!(page.cpu_usage_history.len(), 30);
+        let recent: Vec<f32> = page
+            .cpu_usage_history
+            .iter()
+            .rev()
+            .take(5)
+            .cloned()
+            .collect();
+        assert_eq!(recent, vec![0.33, 0.7125, 0.48, 0.125, 0.0]);
+        assert_eq!(
+            page.cpu_info.as_ref().map(|info| info.logical_cores),
+            Some(4)
+        );
+    }
+}
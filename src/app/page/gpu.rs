@@ -11,39 +11,49 @@ use monitord_protocols::{
     protocols::MonitordServiceClient,
 };
 
-use crate::{app::Message, fl};
+use crate::{app::Message, config::Config, fl};
 
 #[derive(Debug, Clone)]
 pub enum GpuMessage {
     Snapshot(GpuList),
     SelectTab(widget::segmented_button::Entity),
+    ToggleBaseline,
 }
 
 struct GpuDevice {
     info: GpuInfo,
     history: VecDeque<f32>,
+    baseline: Option<Vec<f32>>,
 }
 
 pub struct GpuPage {
     gpu_list: widget::segmented_button::SingleSelectModel,
     name_to_entity: HashMap<String, widget::segmented_button::Entity>,
+    /// Set once monitord has sent a snapshot, which may contain no devices.
+    received: bool,
+    // Configuration data that persists between application runs.
+    config: Config,
 }
 
 impl GpuPage {
-    pub fn new() -> Self {
+    pub fn new(config: Config) -> Self {
         Self {
             gpu_list: widget::segmented_button::SingleSelectModel::default(),
             name_to_entity: HashMap::new(),
+            received: false,
+            config,
         }
     }
 }
 
 impl super::Page for GpuPage {
     fn update(&mut self, msg: Message) -> cosmic::app::Task<Message> {
-        let tasks = Vec::new();
+        let mut tasks = Vec::new();
 
         match msg {
+            Message::UpdateConfig(config) => self.config = config,
             Message::GpuPage(GpuMessage::Snapshot(snapshot)) => {
+                self.received = true;
                 for gpu in snapshot.gpus.iter() {
                     let entity = if let Some(entity) = self.name_to_entity.get(&gpu.name) {
                         entity.clone()
@@ -55,8 +65,14 @@ impl super::Page for GpuPage {
                             .data(GpuDevice {
                                 info: gpu.clone(),
                                 history: VecDeque::from(vec![0.0; 30]),
+                                baseline: None,
                             })
                             .id();
+                        if self.config.selected_tabs.get(super::PageId::Gpu.as_str())
+                            == Some(&gpu.name)
+                        {
+                            self.gpu_list.activate(entity);
+                        }
                         self.name_to_entity.insert(gpu.name.clone(), entity.clone());
                         entity
                     };
@@ -68,7 +84,20 @@ impl super::Page for GpuPage {
                     device.history.pop_front();
                 }
             }
-            Message::GpuPage(GpuMessage::SelectTab(tab)) => self.gpu_list.activate(tab),
+            Message::GpuPage(GpuMessage::SelectTab(tab)) => {
+                self.gpu_list.activate(tab);
+                if let Some(name) = self.gpu_list.text(tab) {
+                    tasks.push(cosmic::app::Task::done(cosmic::Action::App(
+                        Message::SetSelectedTab(super::PageId::Gpu, name.to_owned()),
+                    )));
+                }
+            }
+            Message::GpuPage(GpuMessage::ToggleBaseline) => {
+                let active = self.gpu_list.active();
+                if let Some(device) = self.gpu_list.data_mut::<GpuDevice>(active) {
+                    super::toggle_baseline(&mut device.baseline, &device.history);
+                }
+            }
             _ => {}
         }
 
@@ -76,6 +105,13 @@ impl super::Page for GpuPage {
     }
 
     fn view(&self) -> cosmic::Element<Message> {
+        if !self.received {
+            return super::loading();
+        }
+        if self.gpu_list.iter().next().is_none() {
+            return super::empty(fl!("no-gpus"));
+        }
+
         let theme = cosmic::theme::active();
         let cosmic = theme.cosmic();
 
@@ -91,6 +127,8 @@ impl super::Page for GpuPage {
                     .push(
                         widget::canvas(crate::widget::graph::LineGraph {
                             points: gpu.history.iter().cloned().collect(),
+                            baseline: gpu.baseline.clone(),
+                            palette: self.config.graph_palette,
                         })
                         .width(iced::Length::Fill)
                         .height(iced::Length::Fill),
@@ -241,6 +279,16 @@ impl super::Page for GpuPage {
             .apply(Element::from)
     }
 
+    fn footer(&self) -> Option<Element<Message>> {
+        self.gpu_list.active_data::<GpuDevice>().map(|device| {
+            super::baseline_footer(
+                device.baseline.is_some(),
+                Some(Message::GpuPage(GpuMessage::ToggleBaseline)),
+                vec![],
+            )
+        })
+    }
+
     fn subscription(&self) -> Vec<Subscription<Message>> {
         vec![Subscription::run(|| {
             stream::channel(1, |mut sender| async move {
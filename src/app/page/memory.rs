@@ -10,24 +10,42 @@ use monitord_protocols::{
 };
 use std::collections::VecDeque;
 
-use crate::{app::Message, fl};
+use super::processes::ProcessMessage;
+use crate::{app::Message, config::Config, fl, widget::graph::TreemapEntry};
 
 /// Messages that are emitted relevant to the Memory Page
 #[derive(Debug, Clone)]
 pub enum MemoryMessage {
     Snapshot(MemoryInfo),
+    ToggleBaseline,
+    ToggleTreemap,
 }
 
 pub struct MemoryPage {
     memory_usage_history: VecDeque<f32>,
     memory_info: Option<MemoryInfo>,
+    baseline: Option<Vec<f32>>,
+    /// Shows per-process memory as a treemap in place of the usage graph.
+    show_treemap: bool,
+    /// Processes using the most memory, largest first.
+    top_processes: Vec<TreemapEntry>,
+    /// Whether the Processes page's stream, which the treemap comes from, only carries
+    /// the current user's processes.
+    only_mine: bool,
+    // Configuration data that persists between application runs.
+    config: Config,
 }
 
 impl MemoryPage {
-    pub fn new() -> Self {
+    pub fn new(config: Config) -> Self {
         Self {
             memory_usage_history: VecDeque::from(vec![0.0; 30]),
             memory_info: None,
+            baseline: None,
+            show_treemap: false,
+            top_processes: Vec::new(),
+            only_mine: false,
+            config,
         }
     }
 }
@@ -36,6 +54,7 @@ impl super::Page for MemoryPage {
     fn update(&mut self, msg: Message) -> cosmic::app::Task<Message> {
         let tasks = Vec::new();
         match msg {
+            Message::UpdateConfig(config) => self.config = config,
             Message::MemoryPage(MemoryMessage::Snapshot(snapshot)) => {
                 self.memory_usage_history
                     .push_back(snapshot.memory_load_percent as f32 / 100.0);
@@ -43,6 +62,31 @@ impl super::Page for MemoryPage {
 
                 self.memory_info = Some(snapshot);
             }
+            Message::MemoryPage(MemoryMessage::ToggleBaseline) => {
+                super::toggle_baseline(&mut self.baseline, &self.memory_usage_history);
+            }
+            Message::MemoryPage(MemoryMessage::ToggleTreemap) => {
+                self.show_treemap = !self.show_treemap;
+            }
+            // Lay out from the Processes page's stream rather than opening a second one.
+            Message::ProcessPage(ProcessMessage::ProcessList(list)) => {
+                self.top_processes = list
+                    .processes
+                    .into_iter()
+                    .filter(|process| process.physical_memory_bytes > 0)
+                    .map(|process| TreemapEntry {
+                        pid: process.pid,
+                        label: process.name,
+                        value: process.physical_memory_bytes as f32,
+                    })
+                    .collect();
+                self.top_processes
+                    .sort_by(|a, b| b.value.total_cmp(&a.value));
+                self.top_processes.truncate(TREEMAP_PROCESSES);
+            }
+            Message::ProcessPage(ProcessMessage::OnlyMine(only_mine)) => {
+                self.only_mine = only_mine;
+            }
             _ => {}
         }
 
@@ -55,13 +99,24 @@ impl super::Page for MemoryPage {
             let cosmic = theme.cosmic();
             widget::row()
                 .spacing(cosmic.space_xxs())
-                .push(
+                .push(if self.show_treemap {
+                    widget::canvas(crate::widget::graph::Treemap {
+                        entries: self.top_processes.clone(),
+                        palette: self.config.graph_palette,
+                    })
+                    .width(iced::Length::Fill)
+                    .height(iced::Length::Fill)
+                    .apply(Element::from)
+                } else {
                     widget::canvas(crate::widget::graph::LineGraph {
                         points: self.memory_usage_history.iter().cloned().collect(),
+                        baseline: self.baseline.clone(),
+                        palette: self.config.graph_palette,
                     })
                     .width(iced::Length::Fill)
-                    .height(iced::Length::Fill),
-                )
+                    .height(iced::Length::Fill)
+                    .apply(Element::from)
+                })
                 .push(
                     widget::settings::view_column(vec![
                         widget::settings::section()
@@ -115,10 +170,36 @@ impl super::Page for MemoryPage {
                 )
                 .apply(Element::from)
         } else {
-            widget::horizontal_space().apply(Element::from)
+            super::loading()
         }
     }
 
+    fn footer(&self) -> Option<Element<Message>> {
+        self.memory_info.as_ref().map(|_| {
+            let mut items = Vec::new();
+            // The treemap follows the Processes page's filter, so say when it's narrowed.
+            if self.show_treemap && self.only_mine {
+                items.push(widget::text::caption(fl!("treemap-only-mine")).into());
+            }
+            items.push(
+                if self.show_treemap {
+                    fl!("show-graph")
+                } else {
+                    fl!("show-treemap")
+                }
+                .apply(widget::button::text)
+                .on_press(Message::MemoryPage(MemoryMessage::ToggleTreemap))
+                .into(),
+            );
+
+            super::baseline_footer(
+                self.baseline.is_some(),
+                (!self.show_treemap).then_some(Message::MemoryPage(MemoryMessage::ToggleBaseline)),
+                items,
+            )
+        })
+    }
+
     fn subscription(&self) -> Vec<Subscription<Message>> {
         vec![Subscription::run(|| {
             stream::channel(1, |mut sender| async move {
@@ -148,3 +229,6 @@ impl super::Page for MemoryPage {
         })]
     }
 }
+
+/// Processes laid out in the treemap; the rest are too small to see.
+const TREEMAP_PROCESSES: usize = 64;
@@ -11,39 +11,49 @@ use monitord_protocols::{
     protocols::MonitordServiceClient,
 };
 
-use crate::{app::Message, fl};
+use crate::{app::Message, config::Config, fl};
 
 #[derive(Debug, Clone)]
 pub enum NetworkMessage {
     Snapshot(NetworkList),
     SelectTab(widget::segmented_button::Entity),
+    ToggleBaseline,
 }
 
 struct NetworkDevice {
     info: NetworkInfo,
     history: VecDeque<f32>,
+    baseline: Option<Vec<f32>>,
 }
 
 pub struct NetworkPage {
     net_list: widget::segmented_button::SingleSelectModel,
     name_to_entity: HashMap<String, widget::segmented_button::Entity>,
+    /// Set once monitord has sent a snapshot, which may contain no devices.
+    received: bool,
+    // Configuration data that persists between application runs.
+    config: Config,
 }
 
 impl NetworkPage {
-    pub fn new() -> Self {
+    pub fn new(config: Config) -> Self {
         Self {
             net_list: widget::segmented_button::SingleSelectModel::default(),
             name_to_entity: HashMap::new(),
+            received: false,
+            config,
         }
     }
 }
 
 impl super::Page for NetworkPage {
     fn update(&mut self, msg: Message) -> cosmic::app::Task<Message> {
-        let tasks = Vec::new();
+        let mut tasks = Vec::new();
 
         match msg {
+            Message::UpdateConfig(config) => self.config = config,
             Message::NetworkPage(NetworkMessage::Snapshot(snapshot)) => {
+                self.received = true;
                 for net in snapshot.nets.iter() {
                     let entity = if let Some(entity) = self.name_to_entity.get(&net.interface_name)
                     {
@@ -56,8 +66,17 @@ impl super::Page for NetworkPage {
                             .data(NetworkDevice {
                                 info: net.clone(),
                                 history: VecDeque::from(vec![0.0; 30]),
+                                baseline: None,
                             })
                             .id();
+                        if self
+                            .config
+                            .selected_tabs
+                            .get(super::PageId::Network.as_str())
+                            == Some(&net.interface_name)
+                        {
+                            self.net_list.activate(entity);
+                        }
                         self.name_to_entity
                             .insert(net.interface_name.clone(), entity.clone());
                         entity
@@ -70,7 +89,20 @@ impl super::Page for NetworkPage {
                     device.history.pop_front();
                 }
             }
-            Message::NetworkPage(NetworkMessage::SelectTab(tab)) => self.net_list.activate(tab),
+            Message::NetworkPage(NetworkMessage::SelectTab(tab)) => {
+                self.net_list.activate(tab);
+                if let Some(name) = self.net_list.text(tab) {
+                    tasks.push(cosmic::app::Task::done(cosmic::Action::App(
+                        Message::SetSelectedTab(super::PageId::Network, name.to_owned()),
+                    )));
+                }
+            }
+            Message::NetworkPage(NetworkMessage::ToggleBaseline) => {
+                let active = self.net_list.active();
+                if let Some(device) = self.net_list.data_mut::<NetworkDevice>(active) {
+                    super::toggle_baseline(&mut device.baseline, &device.history);
+                }
+            }
             _ => {}
         }
 
@@ -78,6 +110,13 @@ impl super::Page for NetworkPage {
     }
 
     fn view(&self) -> cosmic::Element<Message> {
+        if !self.received {
+            return super::loading();
+        }
+        if self.net_list.iter().next().is_none() {
+            return super::empty(fl!("no-networks"));
+        }
+
         let theme = cosmic::theme::active();
         let cosmic = theme.cosmic();
 
@@ -91,17 +130,11 @@ impl super::Page for NetworkPage {
                 widget::row()
                     .spacing(cosmic.space_xxs())
                     .push(
-                        widget::canvas(crate::widget::graph::LineGraph {
-                            points: {
-                                let max = net
-                                    .history
-                                    .iter()
-                                    .max_by(|a, b| a.partial_cmp(b).unwrap())
-                                    .unwrap()
-                                    .max(1.0);
-                                net.history.iter().cloned().map(|val| val / max).collect()
-                            },
-                        })
+                        widget::canvas(super::scaled_line_graph(
+                            &net.history,
+                            net.baseline.as_deref(),
+                            self.config.graph_palette,
+                        ))
                         .width(iced::Length::Fill)
                         .height(iced::Length::Fill),
                     )
@@ -199,6 +232,16 @@ impl super::Page for NetworkPage {
             .apply(Element::from)
     }
 
+    fn footer(&self) -> Option<Element<Message>> {
+        self.net_list.active_data::<NetworkDevice>().map(|device| {
+            super::baseline_footer(
+                device.baseline.is_some(),
+                Some(Message::NetworkPage(NetworkMessage::ToggleBaseline)),
+                vec![],
+            )
+        })
+    }
+
     fn subscription(&self) -> Vec<Subscription<Message>> {
         vec![Subscription::run(|| {
             stream::channel(1, |mut sender| async move {
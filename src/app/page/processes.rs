@@ -1,31 +1,74 @@
 mod process;
 use futures_util::SinkExt;
-use process::{ProcessTableCategory, ProcessTableItem};
+pub use process::ProcessTableCategory;
+use process::ProcessTableItem;
 
+use super::cpu::CpuMessage;
+use crate::{
+    app::{palette::PaletteEntry, ContextPage, Message},
+    config::Config,
+    fl, helpers,
+};
 use cosmic::{
     app::{context_drawer, Task},
-    iced::{stream, Length, Subscription},
+    iced::{event, keyboard, stream, Alignment, Event, Length, Subscription},
     prelude::*,
     widget,
 };
-use monitord_protocols::monitord::ProcessSig::{Sigkill, Sigterm};
-use monitord_protocols::monitord::ProcessSigRequest;
-use crate::{
-    app::{ContextPage, Message},
-    config::Config,
-    fl,
-};
+use lazy_static::lazy_static;
+use monitord_protocols::monitord::ProcessSig::{self, Sigkill, Sigterm};
+use monitord_protocols::monitord::{ProcessInfo, ProcessSigRequest};
+use monitord_protocols::protocols::MonitordServiceClient;
+
+lazy_static! {
+    static ref SEARCH_ID: widget::Id = widget::Id::new("PROCESS_SEARCH");
+}
+
 #[derive(Clone, Debug)]
 pub enum ProcessMessage {
     ProcessList(monitord_protocols::monitord::ProcessList),
     SelectProcess(widget::table::Entity),
+    SelectPid(u32),
+    Modifiers(keyboard::Modifiers),
+    Search(String),
+    ShowHidden(bool),
+    OnlyMine(bool),
     SortCategory(ProcessTableCategory),
-    KillProcess(u32),
-    TermProcess(u32),
+    KillProcesses(Vec<u32>),
+    TermProcesses(Vec<u32>),
+}
+
+/// Identifies the selected process across refreshes, as a PID alone may be reused.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct SelectedProcess {
+    pid: u32,
+    name: String,
 }
 
 pub struct ProcessPage {
-    process_model: widget::table::SingleSelectModel<ProcessTableItem, ProcessTableCategory>,
+    process_model: widget::table::MultiSelectModel<ProcessTableItem, ProcessTableCategory>,
+    /// Latest process list from monitord, kept so filters apply without waiting for a refresh.
+    processes: Vec<ProcessInfo>,
+    search: String,
+    /// Temporarily reveals processes matching the hidden list from settings.
+    show_hidden: bool,
+    /// Restricts the list to processes owned by the current user.
+    only_mine: bool,
+    /// Set from toggling Only My Processes until the restarted stream sends its first
+    /// list, so the selection isn't mistaken for processes that ended meanwhile.
+    restarting: bool,
+    /// Logical core count from the CPU stream, used to put the CPU total on the same
+    /// 0-100% scale as the Processor page.
+    logical_cores: u32,
+    /// Name of the current user, if it could be looked up.
+    username: Option<String>,
+    selected: Vec<SelectedProcess>,
+    /// Last clicked process, used as the start of Shift+click ranges.
+    anchor: Option<SelectedProcess>,
+    modifiers: keyboard::Modifiers,
+    selected_ended: bool,
+    /// PID requested through activation, selected once it shows up in the list.
+    pending_pid: Option<u32>,
     show_info: bool,
     // Configuration data that persists between application runs.
     config: Config,
@@ -34,107 +77,354 @@ pub struct ProcessPage {
 impl ProcessPage {
     pub fn new(config: Config) -> Self {
         Self {
-            process_model: widget::table::SingleSelectModel::new(vec![
+            process_model: widget::table::MultiSelectModel::new(vec![
                 ProcessTableCategory::Name,
                 ProcessTableCategory::Cpu,
                 ProcessTableCategory::Gpu,
                 ProcessTableCategory::Mem,
                 ProcessTableCategory::Disk,
             ]),
+            processes: Vec::new(),
+            search: String::new(),
+            show_hidden: false,
+            only_mine: false,
+            restarting: false,
+            username: helpers::current_username(),
+            logical_cores: std::thread::available_parallelism()
+                .map_or(1, |cores| cores.get() as u32),
+            selected: Vec::new(),
+            anchor: None,
+            modifiers: keyboard::Modifiers::empty(),
+            selected_ended: false,
+            pending_pid: None,
             show_info: false,
             config,
         }
     }
 }
 
+impl ProcessPage {
+    /// Whether a process passes the filters and should be shown in the table.
+    fn is_visible(&self, process: &ProcessInfo) -> bool {
+        // Skip entries the daemon caught mid-exit, which arrive without a name.
+        if process.name.is_empty() {
+            return false;
+        }
+
+        // Kernel threads have no command line, unlike userspace processes. Zombies lose
+        // theirs too, but they're still worth seeing.
+        if self.config.hide_kernel_threads
+            && !is_zombie(process)
+            && process
+                .cmdline
+                .as_deref()
+                .is_none_or(|cmdline| cmdline.trim().is_empty())
+        {
+            return false;
+        }
+
+        if !self.show_hidden
+            && self
+                .config
+                .hidden_processes
+                .iter()
+                .any(|pattern| helpers::matches_pattern(pattern, &process.name))
+        {
+            return false;
+        }
+
+        let search = self.search.trim().to_lowercase();
+        search.is_empty()
+            || process.name.to_lowercase().contains(&search)
+            || process
+                .cmdline
+                .as_ref()
+                .is_some_and(|cmdline| cmdline.to_lowercase().contains(&search))
+            || process.pid.to_string().starts_with(&search)
+    }
+
+    /// Repopulates the table from the latest process list, keeping the selection and sort.
+    fn rebuild_model(&mut self) {
+        let old_sort = self.process_model.get_sort();
+        let visible: Vec<ProcessInfo> = self
+            .processes
+            .iter()
+            .filter(|process| self.is_visible(process))
+            .cloned()
+            .collect();
+
+        self.process_model.clear();
+        for process in visible {
+            let is_selected = self.is_selected(&process);
+            let item = ProcessTableItem::new(process, self.config.process_name_source);
+            self.process_model.insert(item).apply(|entity| {
+                if is_selected {
+                    entity.activate();
+                }
+            });
+        }
+
+        // Drop processes that have ended so batch actions only target live ones.
+        if !self.restarting {
+            let had_selection = !self.selected.is_empty();
+            let processes = &self.processes;
+            self.selected.retain(|selected| {
+                processes
+                    .iter()
+                    .any(|process| selected.pid == process.pid && selected.name == process.name)
+            });
+            if had_selection && self.selected.is_empty() {
+                self.selected_ended = true;
+            }
+        }
+
+        if let Some(sort) = old_sort.or(self.config.process_sort) {
+            self.process_model.sort(sort.0, sort.1);
+        } else {
+            self.process_model.sort(ProcessTableCategory::Name, false)
+        }
+    }
+
+    /// Number of shown processes with their CPU share of the whole machine, memory and
+    /// disk rate summed up.
+    fn usage_totals(&self) -> (usize, f32, u64, u64) {
+        let (shown, cpu, mem, disk) = self
+            .process_model
+            .iter()
+            .filter_map(|entity| self.process_model.item(entity))
+            .fold((0, 0.0, 0, 0), |(shown, cpu, mem, disk), item| {
+                let process = &item.process;
+                (
+                    shown + 1,
+                    cpu + process.cpu_usage_percent,
+                    mem + process.physical_memory_bytes,
+                    disk + process.disk_read_bytes_per_sec + process.disk_write_bytes_per_sec,
+                )
+            });
+
+        (shown, cpu / self.logical_cores as f32, mem, disk)
+    }
+
+    /// Summary of the processes currently shown in the table.
+    fn totals(&self) -> Element<Message> {
+        let theme = cosmic::theme::active();
+        let cosmic = theme.cosmic();
+
+        let (shown, cpu, mem, disk) = self.usage_totals();
+
+        widget::row()
+            .spacing(cosmic.space_m())
+            .padding([0, cosmic.space_xxs()])
+            .push(widget::text::caption(fl!(
+                "shown-processes",
+                shown = shown,
+                total = self.processes.len()
+            )))
+            .push(widget::horizontal_space())
+            .push(widget::text::caption(fl!(
+                "total-cpu",
+                usage = helpers::format_number(cpu.round() as f64)
+            )))
+            .push(widget::text::caption(fl!(
+                "total-mem",
+                usage = helpers::get_bytes(mem)
+            )))
+            .push(widget::text::caption(fl!(
+                "total-disk",
+                rate = helpers::get_bytes(disk)
+            )))
+            .apply(Element::from)
+    }
+
+    fn is_selected(&self, process: &ProcessInfo) -> bool {
+        self.selected
+            .iter()
+            .any(|selected| selected.pid == process.pid && selected.name == process.name)
+    }
+
+    /// Table rows that are currently selected.
+    fn selected_items(&self) -> Vec<&ProcessTableItem> {
+        self.process_model
+            .active()
+            .filter_map(|entity| self.process_model.item(entity))
+            .collect()
+    }
+
+    fn selected_pids(&self) -> Vec<u32> {
+        self.selected_items()
+            .iter()
+            .map(|item| item.process.pid)
+            .collect()
+    }
+
+    /// Updates the selection for a click on a row, honouring Ctrl and Shift.
+    fn select(&mut self, entity: widget::table::Entity) {
+        let Some(clicked) = self.process_model.item(entity).map(|item| SelectedProcess {
+            pid: item.process.pid,
+            name: item.process.name.clone(),
+        }) else {
+            return;
+        };
+
+        if self.modifiers.shift() {
+            let rows: Vec<widget::table::Entity> = self.process_model.iter().collect();
+            let anchor = self.anchor.as_ref().and_then(|anchor| {
+                rows.iter().position(|&row| {
+                    self.process_model.item(row).is_some_and(|item| {
+                        item.process.pid == anchor.pid && item.process.name == anchor.name
+                    })
+                })
+            });
+            let end = rows
+                .iter()
+                .position(|&row| row == entity)
+                .unwrap_or_default();
+            let start = anchor.unwrap_or(end);
+
+            self.clear_selection();
+            for &row in &rows[start.min(end)..=start.max(end)] {
+                self.process_model.activate(row);
+            }
+        } else if self.modifiers.control() {
+            // Activating a multi-select row toggles it.
+            self.process_model.activate(entity);
+            self.anchor = Some(clicked);
+        } else {
+            self.clear_selection();
+            self.process_model.activate(entity);
+            self.anchor = Some(clicked);
+        }
+
+        self.selected = self
+            .selected_items()
+            .iter()
+            .map(|item| SelectedProcess {
+                pid: item.process.pid,
+                name: item.process.name.clone(),
+            })
+            .collect();
+        self.selected_ended = false;
+    }
+
+    fn clear_selection(&mut self) {
+        let active: Vec<widget::table::Entity> = self.process_model.active().collect();
+        for entity in active {
+            self.process_model.deactivate(entity);
+        }
+    }
+}
+
 impl super::Page for ProcessPage {
     fn update(&mut self, msg: Message) -> Task<Message> {
         let mut tasks = Vec::new();
         match msg {
-            Message::UpdateConfig(config) => self.config = config,
-            Message::ProcessPage(msg) => match msg {
-                ProcessMessage::ProcessList(processes) => {
-                    let old_sort = self.process_model.get_sort();
-                    let active_process = self
-                        .process_model
-                        .item(self.process_model.active())
-                        .map(|process| process.process.pid);
-                    self.process_model.clear();
-                    for process in processes.processes.iter().cloned() {
-                        let pid = process.pid;
-                        let item = ProcessTableItem::new(process);
-                        self.process_model.insert(item).apply(|entity| {
-                            if let Some(active_pid) = active_process {
-                                if pid == active_pid {
-                                    entity.activate();
-                                }
+            Message::UpdateConfig(config) => {
+                self.config = config;
+                self.rebuild_model();
+            }
+            Message::CpuPage(CpuMessage::Snapshot(info)) => {
+                self.logical_cores = info.logical_cores.max(1) as u32;
+            }
+            Message::ProcessPage(msg) => {
+                match msg {
+                    ProcessMessage::ProcessList(processes) => {
+                        if let Some(pid) = self.pending_pid.take() {
+                            if let Some(process) = processes
+                                .processes
+                                .iter()
+                                .find(|process| process.pid == pid)
+                            {
+                                let selected = SelectedProcess {
+                                    pid,
+                                    name: process.name.clone(),
+                                };
+                                self.selected = vec![selected.clone()];
+                                self.anchor = Some(selected);
                             }
-                        });
+                        }
+                        if std::mem::take(&mut self.restarting) {
+                            // Processes the new filter leaves out are still running, so
+                            // deselect them without reporting them as ended.
+                            let processes = &processes.processes;
+                            self.selected.retain(|selected| {
+                                processes.iter().any(|process| {
+                                    selected.pid == process.pid && selected.name == process.name
+                                })
+                            });
+                        }
+                        self.processes = processes.processes;
+                        self.rebuild_model();
                     }
-                    if let Some(sort) = old_sort {
-                        self.process_model.sort(sort.0, sort.1);
-                    } else {
-                        self.process_model.sort(ProcessTableCategory::Name, false)
+                    ProcessMessage::Search(search) => {
+                        self.search = search;
+                        self.rebuild_model();
                     }
-                }
-                ProcessMessage::SelectProcess(process) => self.process_model.activate(process),
-                ProcessMessage::SortCategory(category) => {
-                    if let Some(sort) = self.process_model.get_sort() {
-                        if sort.0 == category {
-                            self.process_model.sort(category, !sort.1);
-                        } else {
-                            self.process_model.sort(category, false)
-                        }
-                    } else {
-                        self.process_model.sort(category, false)
+                    ProcessMessage::ShowHidden(show_hidden) => {
+                        self.show_hidden = show_hidden;
+                        self.rebuild_model();
+                    }
+                    ProcessMessage::OnlyMine(only_mine) => {
+                        self.only_mine = only_mine;
+                        // Drop the old rows until the restarted stream reports back.
+                        self.restarting = true;
+                        self.processes.clear();
+                        self.rebuild_model();
+                    }
+                    ProcessMessage::SelectProcess(process) => self.select(process),
+                    ProcessMessage::SelectPid(pid) => self.pending_pid = Some(pid),
+                    ProcessMessage::Modifiers(modifiers) => self.modifiers = modifiers,
+                    ProcessMessage::SortCategory(category) => {
+                        let descending = match self.process_model.get_sort() {
+                            Some(sort) if sort.0 == category => !sort.1,
+                            _ => false,
+                        };
+                        self.process_model.sort(category, descending);
+                        tasks.push(Task::done(cosmic::Action::App(Message::SetProcessSort(
+                            category, descending,
+                        ))));
+                    }
+                    ProcessMessage::KillProcesses(pids) => {
+                        tasks.push(Task::future(async move {
+                            match signal_processes(&pids, Sigkill).await {
+                                Ok(()) if pids.len() == 1 => cosmic::Action::App(Message::Toast(
+                                    fl!("process-killed", pid = pids[0]),
+                                )),
+                                Ok(()) => cosmic::Action::App(Message::Toast(fl!(
+                                    "processes-killed",
+                                    count = pids.len()
+                                ))),
+                                Err(why) => cosmic::Action::App(Message::Error(fl!(
+                                    "kill-error",
+                                    error = why.to_string()
+                                ))),
+                            }
+                        }));
+                    }
+                    ProcessMessage::TermProcesses(pids) => {
+                        tasks.push(Task::future(async move {
+                            match signal_processes(&pids, Sigterm).await {
+                                Ok(()) if pids.len() == 1 => cosmic::Action::App(Message::Toast(
+                                    fl!("process-terminated", pid = pids[0]),
+                                )),
+                                Ok(()) => cosmic::Action::App(Message::Toast(fl!(
+                                    "processes-terminated",
+                                    count = pids.len()
+                                ))),
+                                Err(why) => cosmic::Action::App(Message::Error(fl!(
+                                    "term-error",
+                                    error = why.to_string()
+                                ))),
+                            }
+                        }));
                     }
                 }
-                ProcessMessage::KillProcess(pid) => {
-                    tasks.push(Task::future(async move {
-                        use monitord_protocols::protocols::MonitordServiceClient;
-                        let mut client = MonitordServiceClient::connect("http://127.0.0.1:50051")
-                            .await
-                            .unwrap();
-
-                        let request = tonic::Request::new(ProcessSigRequest {
-                            pid,
-                            sig: Sigkill.into()
-                        });
-
-                        let succeeded = client.term_process(request).await.unwrap().into_inner();
-
-                        if succeeded.succeeded {
-                            cosmic::Action::App(Message::NoOp)
-                        } else {
-                            cosmic::Action::App(Message::Error("Failed to kill process".to_owned()))
-                        }
-                    }));
-                }
-                ProcessMessage::TermProcess(pid) => {
-                    tasks.push(Task::future(async move {
-                        use monitord_protocols::protocols::MonitordServiceClient;
-                        let mut client = MonitordServiceClient::connect("http://127.0.0.1:50051")
-                            .await
-                            .unwrap();
-
-                        let request = tonic::Request::new(ProcessSigRequest {
-                            pid,
-                            sig: Sigterm.into()
-                        });
-
-                        let succeeded = client.term_process(request).await.unwrap().into_inner();
-
-                        if succeeded.succeeded {
-                            cosmic::Action::App(Message::NoOp)
-                        } else {
-                            cosmic::Action::App(Message::Error("Failed to term process".to_owned()))
-                        }
-                    }));
-                }
-            },
+            }
             Message::ToggleContextPage(ContextPage::PageAbout) => {
                 self.show_info = true;
             }
+            Message::SearchProcesses => {
+                tasks.push(widget::text_input::focus(SEARCH_ID.clone()));
+            }
 
             _ => {}
         }
@@ -143,23 +433,75 @@ impl super::Page for ProcessPage {
     }
 
     fn view(&self) -> Element<Message> {
-        widget::table(&self.process_model)
-            .on_item_left_click(|entity| {
-                Message::ProcessPage(ProcessMessage::SelectProcess(entity))
-            })
-            .on_category_left_click(|cat| Message::ProcessPage(ProcessMessage::SortCategory(cat)))
-            .apply(widget::scrollable)
-            .id(widget::Id::new("PROCESS_SCROLLABLE"))
-            .height(Length::Fill)
+        let theme = cosmic::theme::active();
+        let cosmic = theme.cosmic();
+
+        widget::column()
+            .spacing(cosmic.space_xs())
+            .push(
+                widget::row()
+                    .spacing(cosmic.space_xs())
+                    .align_y(Alignment::Center)
+                    .push(
+                        widget::search_input(fl!("search-processes"), &self.search)
+                            .id(SEARCH_ID.clone())
+                            .on_input(|search| Message::ProcessPage(ProcessMessage::Search(search)))
+                            .on_clear(Message::ProcessPage(ProcessMessage::Search(String::new()))),
+                    )
+                    .push(
+                        widget::checkbox(fl!("show-hidden"), self.show_hidden).on_toggle(|show| {
+                            Message::ProcessPage(ProcessMessage::ShowHidden(show))
+                        }),
+                    )
+                    .push(
+                        widget::checkbox(fl!("only-mine"), self.only_mine).on_toggle_maybe(
+                            self.username.as_ref().map(|_| {
+                                |only| Message::ProcessPage(ProcessMessage::OnlyMine(only))
+                            }),
+                        ),
+                    )
+                    .push(
+                        widget::checkbox(
+                            fl!("hide-kernel-threads"),
+                            self.config.hide_kernel_threads,
+                        )
+                        .on_toggle(Message::SetHideKernelThreads),
+                    ),
+            )
+            .push(
+                widget::table(&self.process_model)
+                    .on_item_left_click(|entity| {
+                        Message::ProcessPage(ProcessMessage::SelectProcess(entity))
+                    })
+                    .on_category_left_click(|cat| {
+                        Message::ProcessPage(ProcessMessage::SortCategory(cat))
+                    })
+                    .apply(widget::scrollable)
+                    .id(widget::Id::new("PROCESS_SCROLLABLE"))
+                    .height(Length::Fill),
+            )
+            .push(self.totals())
             .apply(Element::from)
     }
 
+    fn header_end(&self) -> Vec<Element<Message>> {
+        if self.config.hide_end_task_button {
+            return vec![];
+        }
+
+        vec![fl!("end-task")
+            .apply(widget::button::text)
+            .on_press_maybe(
+                Some(self.selected_pids())
+                    .filter(|pids| !pids.is_empty())
+                    .map(|pids| Message::ProcessPage(ProcessMessage::TermProcesses(pids))),
+            )
+            .apply(Element::from)]
+    }
+
     fn footer(&self) -> Option<Element<Message>> {
-        if self
-            .process_model
-            .item(self.process_model.active())
-            .is_some()
-        {
+        let pids = self.selected_pids();
+        if !pids.is_empty() {
             let theme = cosmic::theme::active();
             let cosmic = theme.cosmic();
             widget::row()
@@ -167,31 +509,27 @@ impl super::Page for ProcessPage {
                 .spacing(cosmic.space_xxxs())
                 .padding([cosmic.space_xxxs(), cosmic.space_xxs()])
                 .push(
-                    fl!("details")
+                    fl!("hide")
                         .apply(widget::button::text)
-                        .on_press(Message::ToggleContextPage(ContextPage::PageAbout)),
+                        .on_press(Message::HideProcesses(
+                            self.selected_items()
+                                .iter()
+                                .map(|item| item.process.name.clone())
+                                .collect(),
+                        )),
                 )
                 .push(
-                    fl!("kill")
-                        .apply(widget::button::destructive)
-                        .on_press(Message::ProcessPage(ProcessMessage::KillProcess(
-                            self.process_model
-                                .item(self.process_model.active())
-                                .unwrap()
-                                .process
-                                .pid,
-                        ))),
+                    fl!("details")
+                        .apply(widget::button::text)
+                        .on_press(Message::ToggleContextPage(ContextPage::PageAbout)),
                 )
+                .push(fl!("kill").apply(widget::button::destructive).on_press(
+                    Message::ProcessPage(ProcessMessage::KillProcesses(pids.clone())),
+                ))
                 .push(
                     fl!("term")
                         .apply(widget::button::suggested)
-                        .on_press(Message::ProcessPage(ProcessMessage::TermProcess(
-                            self.process_model
-                                .item(self.process_model.active())
-                                .unwrap()
-                                .process
-                                .pid,
-                        ))),
+                        .on_press(Message::ProcessPage(ProcessMessage::TermProcesses(pids))),
                 )
                 .apply(widget::layer_container)
                 .layer(cosmic::cosmic_theme::Layer::Primary)
@@ -203,25 +541,90 @@ impl super::Page for ProcessPage {
     }
 
     fn context_drawer(&self) -> Option<context_drawer::ContextDrawer<Message>> {
-        if let Some(selected) = self.process_model.item(self.process_model.active()) {
+        let selected = self.selected_items();
+        if selected.len() > 1 {
+            Some(context_drawer::context_drawer(
+                widget::settings::section()
+                    .title(fl!("proc-info"))
+                    .add(widget::text::caption(fl!(
+                        "processes-selected",
+                        count = selected.len()
+                    )))
+                    .apply(Element::from),
+                Message::ToggleContextPage(ContextPage::PageAbout),
+            ))
+        } else if let Some(selected) = selected.first() {
             let process = &selected.process;
+            Some(context_drawer::context_drawer(
+                widget::settings::view_column(vec![
+                    widget::settings::section()
+                        .title(fl!("proc-info"))
+                        .add(widget::settings::item(
+                            fl!("internal-name"),
+                            widget::text::caption(process.name.clone()),
+                        ))
+                        .add(widget::settings::item(
+                            fl!("pid"),
+                            widget::text::caption(process.pid.to_string()),
+                        ))
+                        .add(widget::settings::item(
+                            fl!("cmd-line"),
+                            widget::text::caption(
+                                process.cmdline.clone().unwrap_or_default().to_string(),
+                            ),
+                        ))
+                        .add(widget::settings::item(
+                            fl!("status"),
+                            widget::text::caption(process.state.clone()),
+                        ))
+                        .apply(Element::from),
+                    widget::settings::section()
+                        .title(fl!("proc-usage"))
+                        .add(widget::settings::item(
+                            fl!("cpu-usage"),
+                            widget::text::caption(format!(
+                                "{}%",
+                                helpers::format_number(process.cpu_usage_percent as f64)
+                            )),
+                        ))
+                        .add(widget::settings::item(
+                            fl!("mem"),
+                            widget::text::caption(helpers::get_bytes(
+                                process.physical_memory_bytes,
+                            )),
+                        ))
+                        .add(widget::settings::item(
+                            fl!("disk-read-rate"),
+                            widget::text::caption(format!(
+                                "{}/s",
+                                helpers::get_bytes(process.disk_read_bytes_per_sec)
+                            )),
+                        ))
+                        .add(widget::settings::item(
+                            fl!("disk-write-rate"),
+                            widget::text::caption(format!(
+                                "{}/s",
+                                helpers::get_bytes(process.disk_write_bytes_per_sec)
+                            )),
+                        ))
+                        .add_maybe(process.gpu_usage.as_ref().map(|gpu| {
+                            widget::settings::item(
+                                fl!("gpu-usage"),
+                                widget::text::caption(format!(
+                                    "{}%",
+                                    helpers::format_number(gpu.gpu_utilization_percent as f64)
+                                )),
+                            )
+                        }))
+                        .apply(Element::from),
+                ]),
+                Message::ToggleContextPage(ContextPage::PageAbout),
+            ))
+        } else if self.selected_ended {
             Some(context_drawer::context_drawer(
                 widget::settings::section()
                     .title(fl!("proc-info"))
-                    .add(widget::settings::item(
-                        fl!("internal-name"),
-                        widget::text::caption(process.name.clone()),
-                    ))
-                    .add(widget::settings::item(
-                        fl!("cmd-line"),
-                        widget::text::caption(
-                            process.cmdline.clone().unwrap_or_default().to_string(),
-                        ),
-                    ))
-                    .add(widget::settings::item(
-                        fl!("status"),
-                        widget::text::caption(process.state.clone()),
-                    ))
+                    .add(widget::text::caption(fl!("process-ended")))
                     .apply(Element::from),
                 Message::ToggleContextPage(ContextPage::PageAbout),
             ))
@@ -230,42 +633,391 @@ impl super::Page for ProcessPage {
         }
     }
 
+    /// Processes shown in the table, so choosing one always has a row to select.
+    fn palette_entries(&self) -> Vec<PaletteEntry> {
+        self.process_model
+            .iter()
+            .filter_map(|entity| self.process_model.item(entity))
+            .map(|item| &item.process)
+            .map(|process| PaletteEntry {
+                label: process.name.clone(),
+                detail: fl!("command-palette-pid", pid = process.pid),
+                message: Message::ShowProcess(process.pid),
+            })
+            .collect()
+    }
+
     fn subscription(&self) -> Vec<Subscription<Message>> {
-        vec![Subscription::run(|| {
-            stream::channel(1, |mut sender| async move {
-                use monitord_protocols::protocols::MonitordServiceClient;
-                let mut client = MonitordServiceClient::connect("http://127.0.0.1:50051")
-                    .await
-                    .unwrap();
-
-                let request =
-                    tonic::Request::new(monitord_protocols::monitord::ProcessInfoRequest {
-                        interval_ms: 1000,
-                        username_filter: None,
-                        pid_filter: None,
-                        name_filter: None,
-                        sort_by_cpu: true,
-                        sort_by_memory: false,
-                        limit: 10000000,
-                    });
-
-                let mut response = client
-                    .stream_process_info(request)
-                    .await
-                    .unwrap()
-                    .into_inner();
-
-                loop {
-                    let message = response.message().await.unwrap();
-
-                    if let Some(item) = message {
-                        sender
-                            .send(Message::ProcessPage(ProcessMessage::ProcessList(item)))
-                            .await
-                            .unwrap();
-                    }
+        let username = self.only_mine.then(|| self.username.clone()).flatten();
+
+        vec![
+            // Table clicks carry no modifiers, so track them for Ctrl/Shift selection.
+            event::listen_with(|event, _status, _id| match event {
+                Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                    Some(Message::ProcessPage(ProcessMessage::Modifiers(modifiers)))
                 }
+                _ => None,
+            }),
+            // Keyed on the filter so toggling it restarts the stream with the new request.
+            Subscription::run_with_id(
+                ("process-list", username.clone()),
+                stream::channel(1, move |mut sender| async move {
+                    let mut client = MonitordServiceClient::connect("http://127.0.0.1:50051")
+                        .await
+                        .unwrap();
+
+                    let request =
+                        tonic::Request::new(monitord_protocols::monitord::ProcessInfoRequest {
+                            interval_ms: 1000,
+                            username_filter: username,
+                            pid_filter: None,
+                            name_filter: None,
+                            sort_by_cpu: true,
+                            sort_by_memory: false,
+                            limit: 10000000,
+                        });
+
+                    let mut response = client
+                        .stream_process_info(request)
+                        .await
+                        .unwrap()
+                        .into_inner();
+
+                    loop {
+                        let message = response.message().await.unwrap();
+
+                        if let Some(item) = message {
+                            sender
+                                .send(Message::ProcessPage(ProcessMessage::ProcessList(item)))
+                                .await
+                                .unwrap();
+                        }
+                    }
+                }),
+            ),
+        ]
+    }
+}
+
+fn is_zombie(process: &ProcessInfo) -> bool {
+    process.state.eq_ignore_ascii_case("zombie") || process.state == "Z"
+}
+
+/// Asks monitord to send a signal to a process, returning whether it succeeded.
+async fn signal_process(pid: u32, sig: ProcessSig) -> Result<bool, tonic::Status> {
+    let mut client = MonitordServiceClient::connect("http://127.0.0.1:50051")
+        .await
+        .map_err(|err| tonic::Status::unavailable(err.to_string()))?;
+
+    let request = tonic::Request::new(ProcessSigRequest {
+        pid,
+        sig: sig.into(),
+    });
+
+    Ok(client.term_process(request).await?.into_inner().succeeded)
+}
+
+/// Signals each process in turn, failing with the PIDs that could not be signalled.
+async fn signal_processes(pids: &[u32], sig: ProcessSig) -> Result<(), String> {
+    let mut failed = Vec::new();
+    for &pid in pids {
+        match signal_process(pid, sig).await {
+            Ok(true) => {}
+            Ok(false) => failed.push(pid.to_string()),
+            Err(why) => failed.push(format!("{pid} ({})", why.message())),
+        }
+    }
+
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(failed.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::page::{replay, Page};
+    use monitord_protocols::monitord::ProcessList;
+
+    fn process(pid: u32, name: &str, cmdline: &str) -> ProcessInfo {
+        ProcessInfo {
+            pid,
+            name: name.to_owned(),
+            cmdline: Some(cmdline.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    fn send_list(page: &mut ProcessPage, processes: Vec<ProcessInfo>) {
+        let mut list = ProcessList::default();
+        list.processes.extend(processes);
+        let _ = page.update(Message::ProcessPage(ProcessMessage::ProcessList(list)));
+    }
+
+    fn visible_pids(page: &ProcessPage) -> Vec<u32> {
+        let mut pids: Vec<u32> = page
+            .process_model
+            .iter()
+            .filter_map(|entity| page.process_model.item(entity))
+            .map(|item| item.process.pid)
+            .collect();
+        pids.sort();
+        pids
+    }
+
+    fn entity_of(page: &ProcessPage, pid: u32) -> widget::table::Entity {
+        page.process_model
+            .iter()
+            .find(|&entity| {
+                page.process_model
+                    .item(entity)
+                    .is_some_and(|item| item.process.pid == pid)
             })
-        })]
+            .unwrap()
+    }
+
+    fn sample() -> Vec<ProcessInfo> {
+        vec![
+            process(1, "systemd", "/sbin/init"),
+            process(42, "firefox", "/usr/lib/firefox/firefox --new-window"),
+            process(420, "bash", "bash"),
+            process(7, "", ""),
+        ]
+    }
+
+    #[test]
+    fn process_list_fills_table_without_nameless_entries() {
+        let mut page = ProcessPage::new(Config::default());
+        send_list(&mut page, sample());
+
+        assert_eq!(visible_pids(&page), vec![1, 42, 420]);
+        assert_eq!(page.processes.len(), 4);
+    }
+
+    #[test]
+    fn search_matches_name_command_line_and_pid() {
+        let mut page = ProcessPage::new(Config::default());
+        send_list(&mut page, sample());
+
+        let _ = page.update(Message::ProcessPage(ProcessMessage::Search(
+            "FIRE".to_owned(),
+        )));
+        assert_eq!(visible_pids(&page), vec![42]);
+
+        let _ = page.update(Message::ProcessPage(ProcessMessage::Search(
+            "init".to_owned(),
+        )));
+        assert_eq!(visible_pids(&page), vec![1]);
+
+        // PIDs match by prefix.
+        let _ = page.update(Message::ProcessPage(ProcessMessage::Search(
+            "42".to_owned(),
+        )));
+        assert_eq!(visible_pids(&page), vec![42, 420]);
+
+        // The filter keeps applying as new lists arrive.
+        send_list(&mut page, sample());
+        assert_eq!(visible_pids(&page), vec![42, 420]);
+
+        let _ = page.update(Message::ProcessPage(ProcessMessage::Search(String::new())));
+        assert_eq!(visible_pids(&page), vec![1, 42, 420]);
+    }
+
+    #[test]
+    fn selection_survives_refresh() {
+        let mut page = ProcessPage::new(Config::default());
+        send_list(&mut page, sample());
+
+        let entity = entity_of(&page, 42);
+        let _ = page.update(Message::ProcessPage(ProcessMessage::SelectProcess(entity)));
+        assert_eq!(page.selected_pids(), vec![42]);
+
+        send_list(&mut page, sample());
+        assert_eq!(page.selected_pids(), vec![42]);
+        assert!(!page.selected_ended);
+    }
+
+    #[test]
+    fn selection_is_marked_ended_when_process_exits() {
+        let mut page = ProcessPage::new(Config::default());
+        send_list(&mut page, sample());
+
+        let entity = entity_of(&page, 42);
+        let _ = page.update(Message::ProcessPage(ProcessMessage::SelectProcess(entity)));
+
+        send_list(
+            &mut page,
+            sample().into_iter().filter(|p| p.pid != 42).collect(),
+        );
+        assert!(page.selected_pids().is_empty());
+        assert!(page.selected_ended);
+        assert!(page.context_drawer().is_some());
+
+        // A reused PID with a different name is a different process.
+        send_list(&mut page, vec![process(42, "other", "other")]);
+        assert!(page.selected_pids().is_empty());
+        assert!(page.selected_ended);
+    }
+
+    #[test]
+    fn selection_survives_only_mine_toggle() {
+        let mut page = ProcessPage::new(Config::default());
+        send_list(&mut page, sample());
+
+        let entity = entity_of(&page, 42);
+        let _ = page.update(Message::ProcessPage(ProcessMessage::SelectProcess(entity)));
+
+        let _ = page.update(Message::ProcessPage(ProcessMessage::OnlyMine(true)));
+        assert!(visible_pids(&page).is_empty());
+        assert_eq!(page.selected.len(), 1);
+        assert!(!page.selected_ended);
+
+        send_list(&mut page, sample());
+        assert_eq!(page.selected_pids(), vec![42]);
+        assert!(!page.selected_ended);
+
+        // A process the filter leaves out is deselected, not reported as ended.
+        let _ = page.update(Message::ProcessPage(ProcessMessage::OnlyMine(false)));
+        let _ = page.update(Message::ProcessPage(ProcessMessage::OnlyMine(true)));
+        send_list(
+            &mut page,
+            sample().into_iter().filter(|p| p.pid != 42).collect(),
+        );
+        assert!(page.selected.is_empty());
+        assert!(!page.selected_ended);
+    }
+
+    #[test]
+    fn sort_category_toggles_direction_and_survives_refresh() {
+        let mut page = ProcessPage::new(Config::default());
+        send_list(&mut page, sample());
+
+        let _ = page.update(Message::ProcessPage(ProcessMessage::SortCategory(
+            ProcessTableCategory::Cpu,
+        )));
+        assert_eq!(
+            page.process_model.get_sort(),
+            Some((ProcessTableCategory::Cpu, false))
+        );
+
+        let _ = page.update(Message::ProcessPage(ProcessMessage::SortCategory(
+            ProcessTableCategory::Cpu,
+        )));
+        assert_eq!(
+            page.process_model.get_sort(),
+            Some((ProcessTableCategory::Cpu, true))
+        );
+
+        send_list(&mut page, sample());
+        assert_eq!(
+            page.process_model.get_sort(),
+            Some((ProcessTableCategory::Cpu, true))
+        );
+
+        // Switching columns starts over in ascending order.
+        let _ = page.update(Message::ProcessPage(ProcessMessage::SortCategory(
+            ProcessTableCategory::Mem,
+        )));
+        assert_eq!(
+            page.process_model.get_sort(),
+            Some((ProcessTableCategory::Mem, false))
+        );
+    }
+
+    #[test]
+    fn replayed_sort_stays_monotonic_across_ticks() {
+        let mut page = ProcessPage::new(Config::default());
+        let mut messages = replay::load(replay::DESKTOP).into_iter();
+
+        // Sort once the first tick has filled the table, like a user would.
+        replay::replay(&mut page, messages.by_ref().take(2));
+        let _ = page.update(Message::ProcessPage(ProcessMessage::SortCategory(
+            ProcessTableCategory::Cpu,
+        )));
+
+        let mut direction = None;
+        for message in messages {
+            let is_list = matches!(
+                message,
+                Message::ProcessPage(ProcessMessage::ProcessList(_))
+            );
+            replay::replay(&mut page, [message]);
+            if !is_list {
+                continue;
+            }
+
+            assert_eq!(
+                page.process_model.get_sort().map(|sort| sort.0),
+                Some(ProcessTableCategory::Cpu)
+            );
+            let usage: Vec<f32> = page
+                .process_model
+                .iter()
+                .filter_map(|entity| page.process_model.item(entity))
+                .map(|item| item.process.cpu_usage_percent)
+                .collect();
+            let ascending = usage.windows(2).all(|pair| pair[0] <= pair[1]);
+            let descending = usage.windows(2).all(|pair| pair[0] >= pair[1]);
+            assert!(ascending || descending, "rows out of order: {usage:?}");
+
+            // Refreshes must not flip the direction the user picked.
+            if ascending != descending {
+                assert_eq!(*direction.get_or_insert(ascending), ascending);
+            }
+        }
+        assert!(direction.is_some());
+    }
+
+    #[test]
+    fn replayed_totals_match_recording() {
+        let messages = replay::load(replay::DESKTOP);
+        let last = replay::process_lists(&messages)
+            .last()
+            .unwrap()
+            .processes
+            .clone();
+
+        let mut page = ProcessPage::new(Config::default());
+        replay::replay(&mut page, messages);
+
+        let (shown, cpu, mem, disk) = page.usage_totals();
+        assert_eq!(shown, last.len());
+        let expected_cpu: f32 = last.iter().map(|p| p.cpu_usage_percent).sum::<f32>() / 4.0;
+        assert!((cpu - expected_cpu).abs() < 0.01);
+        assert_eq!(
+            mem,
+            last.iter().map(|p| p.physical_memory_bytes).sum::<u64>()
+        );
+        assert_eq!(
+            disk,
+            last.iter()
+                .map(|p| p.disk_read_bytes_per_sec + p.disk_write_bytes_per_sec)
+                .sum::<u64>()
+        );
+
+        // Totals only cover what the search leaves in the table.
+        let _ = page.update(Message::ProcessPage(ProcessMessage::Search(
+            "cargo".to_owned(),
+        )));
+        let (shown, cpu, _, disk) = page.usage_totals();
+        assert_eq!(shown, 1);
+        assert!((cpu - 115.2 / 4.0).abs() < 0.01);
+        assert_eq!(disk, 2097152 + 1048576);
+    }
+
+    #[test]
+    fn palette_offers_shown_processes() {
+        let mut page = ProcessPage::new(Config::default());
+        send_list(&mut page, sample());
+        let _ = page.update(Message::ProcessPage(ProcessMessage::Search(
+            "fire".to_owned(),
+        )));
+
+        let entries = page.palette_entries();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].label, "firefox");
+        assert!(matches!(entries[0].message, Message::ShowProcess(42)));
     }
 }
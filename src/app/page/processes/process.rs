@@ -1,7 +1,8 @@
-use crate::{fl, helpers};
+use crate::{config::ProcessNameSource, fl, helpers};
 use cosmic::{iced::Length, widget};
 use lazy_static::lazy_static;
 use monitord_protocols::monitord::ProcessInfo;
+use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 
 lazy_static! {
@@ -22,9 +23,9 @@ pub struct ProcessTableItem {
 }
 
 impl ProcessTableItem {
-    pub fn new(process: ProcessInfo) -> Self {
+    pub fn new(process: ProcessInfo, name_source: ProcessNameSource) -> Self {
         Self {
-            name: process.name.clone().into(),
+            name: process_name(&process, name_source).into(),
             cpu: format!("{}%", process.cpu_usage_percent.round()).into(),
             gpu: format!(
                 "{}%",
@@ -49,6 +50,57 @@ impl ProcessTableItem {
     }
 }
 
+/// Resolves the name shown for a process, falling back to the kernel's name when the
+/// requested source is unavailable.
+pub fn process_name(process: &ProcessInfo, source: ProcessNameSource) -> String {
+    let cmdline = process
+        .cmdline
+        .as_deref()
+        .map(str::trim)
+        .filter(|cmdline| !cmdline.is_empty());
+
+    match (source, cmdline) {
+        (ProcessNameSource::Executable, Some(cmdline)) => executable(cmdline)
+            // Split on both separators so Wine's Windows paths resolve to the .exe name.
+            .rsplit(['/', '\\'])
+            .next()
+            .filter(|exe| !exe.is_empty())
+            .unwrap_or(&process.name)
+            .to_owned(),
+        (ProcessNameSource::CommandLine, Some(cmdline)) => cmdline.to_owned(),
+        _ => process.name.clone(),
+    }
+}
+
+/// argv[0] of a command line, read from the text alone. Paths with spaces are only kept
+/// whole when the arguments are NUL-separated, quoted, or a Wine `.exe` path.
+fn executable(cmdline: &str) -> &str {
+    // Arguments are NUL-separated when the command line is passed through untouched.
+    if let Some((argv0, _)) = cmdline.split_once('\0') {
+        return argv0;
+    }
+
+    if let Some(quoted) = cmdline.strip_prefix('"') {
+        if let Some((argv0, _)) = quoted.split_once('"') {
+            return argv0;
+        }
+    }
+
+    // Windows paths under Wine, e.g. `C:\Program Files\Foo\foo.exe`, end at the `.exe`.
+    if cmdline.get(1..3) == Some(":\\") {
+        let lower = cmdline.to_ascii_lowercase();
+        let end = lower
+            .match_indices(".exe")
+            .map(|(index, _)| index + ".exe".len())
+            .find(|&end| lower[end..].chars().next().is_none_or(char::is_whitespace));
+        if let Some(end) = end {
+            return &cmdline[..end];
+        }
+    }
+
+    cmdline.split_whitespace().next().unwrap_or_default()
+}
+
 impl widget::table::ItemInterface<ProcessTableCategory> for ProcessTableItem {
     fn get_icon(&self, category: ProcessTableCategory) -> Option<widget::Icon> {
         match category {
@@ -108,7 +160,7 @@ impl widget::table::ItemInterface<ProcessTableCategory> for ProcessTableItem {
     }
 }
 
-#[derive(Default, Debug, Hash, PartialEq, Eq, Clone, Copy)]
+#[derive(Default, Debug, Hash, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
 pub enum ProcessTableCategory {
     #[default]
     Name,
@@ -145,3 +197,69 @@ impl widget::table::ItemCategory for ProcessTableCategory {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(name: &str, cmdline: &str) -> ProcessInfo {
+        ProcessInfo {
+            name: name.to_owned(),
+            cmdline: Some(cmdline.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn executable_splits_plain_command_lines() {
+        assert_eq!(executable("/usr/bin/bash --login"), "/usr/bin/bash");
+        assert_eq!(executable("bash"), "bash");
+        assert_eq!(executable(""), "");
+    }
+
+    #[test]
+    fn executable_keeps_spaces_it_can_tell_apart() {
+        assert_eq!(executable("/opt/My App/app\0--flag\0"), "/opt/My App/app");
+        assert_eq!(executable("\"/opt/My App/app\" --flag"), "/opt/My App/app");
+        assert_eq!(
+            executable("C:\\Program Files\\Foo Bar\\foo.exe /silent"),
+            "C:\\Program Files\\Foo Bar\\foo.exe"
+        );
+        // A `.exe` inside a directory name doesn't end the path.
+        assert_eq!(
+            executable("C:\\games\\x.exe.d\\run.EXE -w"),
+            "C:\\games\\x.exe.d\\run.EXE"
+        );
+    }
+
+    #[test]
+    fn process_name_follows_source() {
+        let wine = process(
+            "foo.exe",
+            "C:\\Program Files\\Foo Bar\\Foo Launcher.exe --quiet",
+        );
+        assert_eq!(
+            process_name(&wine, ProcessNameSource::Executable),
+            "Foo Launcher.exe"
+        );
+        assert_eq!(process_name(&wine, ProcessNameSource::Comm), "foo.exe");
+        assert_eq!(
+            process_name(&wine, ProcessNameSource::CommandLine),
+            "C:\\Program Files\\Foo Bar\\Foo Launcher.exe --quiet"
+        );
+
+        let native = process("firefox", "/usr/lib/firefox/firefox\0--new-window\0");
+        assert_eq!(
+            process_name(&native, ProcessNameSource::Executable),
+            "firefox"
+        );
+    }
+
+    #[test]
+    fn process_name_falls_back_to_comm() {
+        let kernel = process("kworker/0:1", "  ");
+        for source in ProcessNameSource::ALL {
+            assert_eq!(process_name(&kernel, source), "kworker/0:1");
+        }
+    }
+}
@@ -0,0 +1,109 @@
+//! Replays recorded monitord data through pages so their update logic can be tested
+//! without a running daemon.
+//!
+//! Messages go straight to [`Page::update`], and the tasks it returns are dropped. Routing
+//! in `AppModel::update` is not exercised, such as persisting `SetProcessSort`, turning
+//! `ShowProcess` into `SelectPid` or rebuilding pages on `UpdateConfig`; tests that need
+//! those send the follow-up messages themselves.
+//!
+//! Recordings are plain text, one tick per keyword line:
+//!
+//! ```text
+//! # comments and blank lines are ignored
+//! cpu <global utilization %> <logical cores>
+//! processes
+//!     <pid> <name> <cpu %> <memory bytes> <read B/s> <write B/s>
+//! ```
+//!
+//! Every row following `processes` belongs to that process list, until the next keyword.
+
+use monitord_protocols::monitord::{CpuInfo, ProcessInfo, ProcessList};
+
+use super::{cpu::CpuMessage, processes::ProcessMessage, Page};
+use crate::app::Message;
+
+/// A desktop session of a few seconds, with processes starting and exiting.
+pub const DESKTOP: &str = include_str!("replay/desktop.rec");
+
+/// Parses a recording into the messages its subscriptions would have sent.
+pub fn load(recording: &str) -> Vec<Message> {
+    let mut messages = Vec::new();
+    let mut processes: Option<ProcessList> = None;
+
+    for line in recording.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut fields = line.split_whitespace();
+        let keyword = fields.next().unwrap();
+        if keyword.starts_with(|c: char| c.is_ascii_digit()) {
+            let list = processes
+                .as_mut()
+                .unwrap_or_else(|| panic!("process row outside a list: {line:?}"));
+            let mut field = || {
+                fields
+                    .next()
+                    .unwrap_or_else(|| panic!("short row: {line:?}"))
+            };
+            let name = field().to_owned();
+            // Recordings only hold userspace processes, which always have a command line.
+            list.processes.push(ProcessInfo {
+                pid: keyword.parse().unwrap(),
+                cmdline: Some(name.clone()),
+                name,
+                cpu_usage_percent: field().parse().unwrap(),
+                physical_memory_bytes: field().parse().unwrap(),
+                disk_read_bytes_per_sec: field().parse().unwrap(),
+                disk_write_bytes_per_sec: field().parse().unwrap(),
+                ..Default::default()
+            });
+            continue;
+        }
+
+        if let Some(list) = processes.take() {
+            messages.push(Message::ProcessPage(ProcessMessage::ProcessList(list)));
+        }
+
+        match keyword {
+            "processes" => processes = Some(ProcessList::default()),
+            "cpu" => {
+                let mut field = || {
+                    fields
+                        .next()
+                        .unwrap_or_else(|| panic!("short row: {line:?}"))
+                };
+                messages.push(Message::CpuPage(CpuMessage::Snapshot(CpuInfo {
+                    global_utilization_percent: field().parse().unwrap(),
+                    logical_cores: field().parse().unwrap(),
+                    ..Default::default()
+                })));
+            }
+            _ => panic!("unknown recording keyword {keyword:?}"),
+        }
+    }
+
+    if let Some(list) = processes {
+        messages.push(Message::ProcessPage(ProcessMessage::ProcessList(list)));
+    }
+
+    messages
+}
+
+/// Feeds messages to a page in order, dropping the tasks it returns.
+pub fn replay(page: &mut impl Page, messages: impl IntoIterator<Item = Message>) {
+    for message in messages {
+        let _ = page.update(message);
+    }
+}
+
+/// The process lists in a recording, for computing expected values.
+pub fn process_lists(messages: &[Message]) -> Vec<&ProcessList> {
+    messages
+        .iter()
+        .filter_map(|message| match message {
+            Message::ProcessPage(ProcessMessage::ProcessList(list)) => Some(list),
+            _ => None,
+        })
+        .collect()
+}
@@ -7,43 +7,61 @@ use cosmic::{
 };
 use futures_util::SinkExt;
 use monitord_protocols::{
-    monitord::{SnapshotRequest, StorageInfo, StorageList},
+    monitord::{ProcessInfo, SnapshotRequest, StorageInfo, StorageList},
     protocols::MonitordServiceClient,
 };
 
-use crate::{app::Message, fl};
+use super::processes::ProcessMessage;
+use crate::{app::Message, config::Config, fl};
 
 #[derive(Debug, Clone)]
 pub enum StorageMessage {
     Snapshot(StorageList),
     SelectTab(widget::segmented_button::Entity),
+    ToggleBaseline,
 }
 
 struct StorageDevice {
     info: StorageInfo,
     history: VecDeque<f32>,
+    baseline: Option<Vec<f32>>,
 }
 
 pub struct StoragePage {
     storage_list: widget::segmented_button::SingleSelectModel,
     name_to_entity: HashMap<String, widget::segmented_button::Entity>,
+    /// Set once monitord has sent a snapshot, which may contain no devices.
+    received: bool,
+    /// Processes with the highest combined read and write rates.
+    top_processes: Vec<ProcessInfo>,
+    /// Whether the Processes page's stream, which the ranking comes from, only carries
+    /// the current user's processes.
+    only_mine: bool,
+    // Configuration data that persists between application runs.
+    config: Config,
 }
 
 impl StoragePage {
-    pub fn new() -> Self {
+    pub fn new(config: Config) -> Self {
         Self {
             storage_list: widget::segmented_button::SingleSelectModel::default(),
             name_to_entity: HashMap::new(),
+            received: false,
+            top_processes: Vec::new(),
+            only_mine: false,
+            config,
         }
     }
 }
 
 impl super::Page for StoragePage {
     fn update(&mut self, msg: Message) -> cosmic::app::Task<Message> {
-        let tasks = Vec::new();
+        let mut tasks = Vec::new();
 
         match msg {
+            Message::UpdateConfig(config) => self.config = config,
             Message::StoragePage(StorageMessage::Snapshot(snapshot)) => {
+                self.received = true;
                 for storage in snapshot.storages.iter() {
                     let entity = if let Some(entity) = self.name_to_entity.get(&storage.device_name)
                     {
@@ -56,8 +74,17 @@ impl super::Page for StoragePage {
                             .data(StorageDevice {
                                 info: storage.clone(),
                                 history: VecDeque::from(vec![0.0; 30]),
+                                baseline: None,
                             })
                             .id();
+                        if self
+                            .config
+                            .selected_tabs
+                            .get(super::PageId::Storage.as_str())
+                            == Some(&storage.device_name)
+                        {
+                            self.storage_list.activate(entity);
+                        }
                         self.name_to_entity
                             .insert(storage.device_name.clone(), entity.clone());
                         entity
@@ -70,7 +97,31 @@ impl super::Page for StoragePage {
                     device.history.pop_front();
                 }
             }
-            Message::StoragePage(StorageMessage::SelectTab(tab)) => self.storage_list.activate(tab),
+            // Rank from the Processes page's stream rather than opening a second one.
+            Message::ProcessPage(ProcessMessage::ProcessList(list)) => {
+                let mut processes = list.processes;
+                processes.retain(|process| disk_rate(process) > 0);
+                processes.sort_by_key(|process| std::cmp::Reverse(disk_rate(process)));
+                processes.truncate(TOP_PROCESSES);
+                self.top_processes = processes;
+            }
+            Message::ProcessPage(ProcessMessage::OnlyMine(only_mine)) => {
+                self.only_mine = only_mine;
+            }
+            Message::StoragePage(StorageMessage::SelectTab(tab)) => {
+                self.storage_list.activate(tab);
+                if let Some(name) = self.storage_list.text(tab) {
+                    tasks.push(cosmic::app::Task::done(cosmic::Action::App(
+                        Message::SetSelectedTab(super::PageId::Storage, name.to_owned()),
+                    )));
+                }
+            }
+            Message::StoragePage(StorageMessage::ToggleBaseline) => {
+                let active = self.storage_list.active();
+                if let Some(device) = self.storage_list.data_mut::<StorageDevice>(active) {
+                    super::toggle_baseline(&mut device.baseline, &device.history);
+                }
+            }
             _ => {}
         }
 
@@ -78,6 +129,13 @@ impl super::Page for StoragePage {
     }
 
     fn view(&self) -> cosmic::Element<Message> {
+        if !self.received {
+            return super::loading();
+        }
+        if self.storage_list.iter().next().is_none() {
+            return super::empty(fl!("no-storage"));
+        }
+
         let theme = cosmic::theme::active();
         let cosmic = theme.cosmic();
 
@@ -94,23 +152,11 @@ impl super::Page for StoragePage {
                         widget::row()
                             .spacing(cosmic.space_xxs())
                             .push(
-                                widget::canvas(crate::widget::graph::LineGraph {
-                                    points: {
-                                        let max = storage
-                                            .history
-                                            .iter()
-                                            .max_by(|a, b| a.partial_cmp(b).unwrap())
-                                            .unwrap()
-                                            .max(1.0);
-                                        tracing::info!("{}", max);
-                                        storage
-                                            .history
-                                            .iter()
-                                            .cloned()
-                                            .map(|val| val / max)
-                                            .collect()
-                                    },
-                                })
+                                widget::canvas(super::scaled_line_graph(
+                                    &storage.history,
+                                    storage.baseline.as_deref(),
+                                    self.config.graph_palette,
+                                ))
                                 .width(iced::Length::Fill)
                                 .height(iced::Length::Fill),
                             )
@@ -236,6 +282,32 @@ impl super::Page for StoragePage {
                                             )
                                         }))
                                         .apply(Element::from),
+                                    self.top_processes
+                                        .iter()
+                                        .fold(
+                                            widget::settings::section().title(if self.only_mine {
+                                                fl!("top-disk-processes-mine")
+                                            } else {
+                                                fl!("top-disk-processes")
+                                            }),
+                                            |section, process| {
+                                                section.add(widget::settings::item_row(vec![
+                                                    widget::button::link(process.name.clone())
+                                                        .on_press(Message::ShowProcess(process.pid))
+                                                        .padding(0)
+                                                        .apply(Element::from),
+                                                    widget::horizontal_space().into(),
+                                                    format!(
+                                                        "{}/s",
+                                                        disk_rate(process)
+                                                            .apply(crate::helpers::get_bytes)
+                                                    )
+                                                    .apply(widget::text::body)
+                                                    .into(),
+                                                ]))
+                                            },
+                                        )
+                                        .apply(Element::from),
                                 ])
                                 .apply(widget::scrollable),
                             )
@@ -245,6 +317,18 @@ impl super::Page for StoragePage {
             .apply(Element::from)
     }
 
+    fn footer(&self) -> Option<Element<Message>> {
+        self.storage_list
+            .active_data::<StorageDevice>()
+            .map(|device| {
+                super::baseline_footer(
+                    device.baseline.is_some(),
+                    Some(Message::StoragePage(StorageMessage::ToggleBaseline)),
+                    vec![],
+                )
+            })
+    }
+
     fn subscription(&self) -> Vec<Subscription<Message>> {
         vec![Subscription::run(|| {
             stream::channel(1, |mut sender| async move {
@@ -274,3 +358,79 @@ impl super::Page for StoragePage {
         })]
     }
 }
+
+/// Number of processes listed under the device details.
+const TOP_PROCESSES: usize = 8;
+
+/// Combined read and write rate of a process in bytes per second.
+fn disk_rate(process: &ProcessInfo) -> u64 {
+    process.disk_read_bytes_per_sec + process.disk_write_bytes_per_sec
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::page::{replay, Page};
+    use monitord_protocols::monitord::ProcessList;
+
+    #[test]
+    fn replayed_ranking_follows_latest_list() {
+        let mut page = StoragePage::new(Config::default());
+        replay::replay(&mut page, replay::load(replay::DESKTOP));
+
+        // Earlier ticks don't linger, and only cargo touches the disk on the last one.
+        let pids: Vec<u32> = page.top_processes.iter().map(|p| p.pid).collect();
+        assert_eq!(pids, vec![3005]);
+    }
+
+    #[test]
+    fn ranking_is_sorted_and_capped() {
+        let messages = replay::load(replay::DESKTOP);
+        let lists = replay::process_lists(&messages);
+
+        for list in lists {
+            let mut page = StoragePage::new(Config::default());
+            let _ = page.update(Message::ProcessPage(ProcessMessage::ProcessList(
+                list.clone(),
+            )));
+
+            assert!(page.top_processes.len() <= TOP_PROCESSES);
+            assert!(page.top_processes.iter().all(|p| disk_rate(p) > 0));
+            assert!(page
+                .top_processes
+                .windows(2)
+                .all(|pair| disk_rate(&pair[0]) >= disk_rate(&pair[1])));
+            let busy = list.processes.iter().filter(|p| disk_rate(p) > 0).count();
+            assert_eq!(page.top_processes.len(), busy.min(TOP_PROCESSES));
+        }
+    }
+
+    #[test]
+    fn ranking_keeps_the_busiest() {
+        let mut list = ProcessList::default();
+        list.processes.extend((1..=12).map(|pid| ProcessInfo {
+            pid,
+            name: format!("worker{pid}"),
+            disk_read_bytes_per_sec: pid as u64 * 1024,
+            disk_write_bytes_per_sec: 512,
+            ..Default::default()
+        }));
+
+        let mut page = StoragePage::new(Config::default());
+        let _ = page.update(Message::ProcessPage(ProcessMessage::ProcessList(list)));
+
+        let pids: Vec<u32> = page.top_processes.iter().map(|p| p.pid).collect();
+        assert_eq!(pids, vec![12, 11, 10, 9, 8, 7, 6, 5]);
+    }
+
+    #[test]
+    fn ranking_follows_only_mine_filter() {
+        let mut page = StoragePage::new(Config::default());
+        assert!(!page.only_mine);
+
+        let _ = page.update(Message::ProcessPage(ProcessMessage::OnlyMine(true)));
+        assert!(page.only_mine);
+        let _ = page.update(Message::ProcessPage(ProcessMessage::OnlyMine(false)));
+        assert!(!page.only_mine);
+    }
+}
@@ -92,7 +92,7 @@ impl super::Page for SystemPage {
             .apply(widget::scrollable)
             .apply(Element::from)
         } else {
-            widget::horizontal_space().apply(Element::from)
+            super::loading()
         }
     }
 
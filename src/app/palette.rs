@@ -0,0 +1,145 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! Ctrl+K command palette, searching the nav pages, settings actions and whatever entries
+//! each page provides through [`Page::palette_entries`](super::page::Page::palette_entries).
+
+use super::Message;
+use crate::fl;
+use cosmic::{iced::Length, prelude::*, widget};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    pub static ref INPUT_ID: widget::Id = widget::Id::new("COMMAND_PALETTE");
+}
+
+/// Results listed at once; typing more narrows down the rest.
+const MAX_RESULTS: usize = 8;
+
+/// Something the palette can jump to or run.
+#[derive(Clone, Debug)]
+pub struct PaletteEntry {
+    pub label: String,
+    /// Secondary text shown next to the label, such as a process's PID.
+    pub detail: String,
+    /// Sent when the entry is chosen.
+    pub message: Message,
+}
+
+/// Entries whose label or detail contains the query, those whose label starts with it
+/// listed first. Entries otherwise keep the order their providers gave them.
+pub fn search(entries: Vec<PaletteEntry>, query: &str) -> Vec<PaletteEntry> {
+    let query = query.trim().to_lowercase();
+    let mut matches: Vec<(bool, PaletteEntry)> = entries
+        .into_iter()
+        .filter_map(|entry| {
+            let label = entry.label.to_lowercase();
+            if label.starts_with(&query) {
+                Some((true, entry))
+            } else if label.contains(&query) || entry.detail.to_lowercase().contains(&query) {
+                Some((false, entry))
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    matches.sort_by_key(|(prefix, _)| !prefix);
+    matches
+        .into_iter()
+        .map(|(_, entry)| entry)
+        .take(MAX_RESULTS)
+        .collect()
+}
+
+pub fn view<'a>(query: &'a str, results: Vec<PaletteEntry>) -> Element<'a, Message> {
+    let theme = cosmic::theme::active();
+    let cosmic = theme.cosmic();
+
+    let list: Element<Message> = if results.is_empty() {
+        widget::text::body(fl!("command-palette-no-results")).into()
+    } else {
+        results
+            .into_iter()
+            .fold(widget::column(), |column, entry| {
+                column.push(
+                    widget::button::custom(
+                        widget::row()
+                            .spacing(cosmic.space_xs())
+                            .push(widget::text::body(entry.label))
+                            .push(widget::horizontal_space())
+                            .push(widget::text::caption(entry.detail)),
+                    )
+                    .class(cosmic::theme::Button::MenuItem)
+                    .width(Length::Fill)
+                    .on_press(Message::RunPaletteEntry(Box::new(entry.message))),
+                )
+            })
+            .into()
+    };
+
+    widget::dialog()
+        .title(fl!("command-palette"))
+        .control(
+            widget::column()
+                .spacing(cosmic.space_xs())
+                .push(
+                    widget::search_input(fl!("command-palette-placeholder"), query)
+                        .id(INPUT_ID.clone())
+                        .on_input(Message::PaletteInput)
+                        .on_clear(Message::PaletteInput(String::new()))
+                        .on_submit(|_| Message::SubmitPalette),
+                )
+                .push(list),
+        )
+        .secondary_action(widget::button::standard(fl!("cancel")).on_press(Message::ClosePalette))
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(label: &str, detail: &str) -> PaletteEntry {
+        PaletteEntry {
+            label: label.to_owned(),
+            detail: detail.to_owned(),
+            message: Message::NoOp,
+        }
+    }
+
+    fn labels(entries: &[PaletteEntry]) -> Vec<&str> {
+        entries.iter().map(|entry| entry.label.as_str()).collect()
+    }
+
+    #[test]
+    fn prefix_matches_come_first() {
+        let entries = vec![
+            entry("Hide Kernel Threads", "On"),
+            entry("Processor", "Page"),
+            entry("Processes", "Page"),
+            entry("pipewire", "PID 812"),
+        ];
+
+        let results = search(entries, "  PROC ");
+        assert_eq!(labels(&results), vec!["Processor", "Processes"]);
+    }
+
+    #[test]
+    fn detail_matches_are_included() {
+        let entries = vec![entry("firefox", "PID 2210"), entry("cargo", "PID 3005")];
+
+        let results = search(entries, "3005");
+        assert_eq!(labels(&results), vec!["cargo"]);
+    }
+
+    #[test]
+    fn empty_query_lists_first_entries() {
+        let entries = (0..20)
+            .map(|pid| entry("bash", &format!("PID {pid}")))
+            .collect();
+
+        let results = search(entries, "");
+        assert_eq!(results.len(), MAX_RESULTS);
+        assert_eq!(results[0].detail, "PID 0");
+    }
+}
@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! Manages the XDG autostart entry used to launch Observatory in the background on login.
+
+use std::path::PathBuf;
+
+const DESKTOP_ENTRY: &str = "[Desktop Entry]
+Name=Observatory
+Comment=A system monitor for the COSMIC™ desktop
+Type=Application
+Icon=utilities-system-monitor
+Exec=observatory --background
+Terminal=false
+NoDisplay=true
+X-GNOME-Autostart-enabled=true
+";
+
+/// Location of the autostart entry, following `$XDG_CONFIG_HOME` when set.
+fn entry_path() -> Option<PathBuf> {
+    let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+
+    Some(
+        config_dir
+            .join("autostart")
+            .join("io.github.CosmicUtils.Observatory.desktop"),
+    )
+}
+
+/// Whether the autostart entry is installed.
+pub fn is_enabled() -> bool {
+    entry_path().is_some_and(|path| path.exists())
+}
+
+/// Installs or removes the autostart entry.
+pub fn set_enabled(enabled: bool) -> std::io::Result<()> {
+    let path = entry_path().ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "could not determine the user config directory",
+        )
+    })?;
+
+    if enabled {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, DESKTOP_ENTRY)
+    } else {
+        match std::fs::remove_file(path) {
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            result => result,
+        }
+    }
+}
@@ -1,10 +1,53 @@
 // SPDX-License-Identifier: MPL-2.0
 
+use std::collections::HashMap;
+
 use cosmic::cosmic_config::{self, cosmic_config_derive::CosmicConfigEntry, CosmicConfigEntry};
+use serde::{Deserialize, Serialize};
+
+use crate::app::page::processes::ProcessTableCategory;
 
 #[derive(Debug, Default, Clone, CosmicConfigEntry, Eq, PartialEq)]
 #[version = 1]
 pub struct Config {
     pub scale_by_core: bool,
     pub multicore_view: bool,
+    pub run_in_background: bool,
+    pub hide_end_task_button: bool,
+    pub graph_palette: GraphPalette,
+    pub process_name_source: ProcessNameSource,
+    pub hidden_processes: Vec<String>,
+    pub hide_kernel_threads: bool,
+    /// Nav page that was open when the app was last used.
+    pub last_page: String,
+    /// Sort column and direction of the process table.
+    pub process_sort: Option<(ProcessTableCategory, bool)>,
+    /// Name of the selected device tab, keyed by page.
+    pub selected_tabs: HashMap<String, String>,
+}
+
+/// Color palette used to draw resource graphs.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum GraphPalette {
+    #[default]
+    Accent,
+    HighContrast,
+    ColorblindSafe,
+}
+
+impl GraphPalette {
+    pub const ALL: [Self; 3] = [Self::Accent, Self::HighContrast, Self::ColorblindSafe];
+}
+
+/// Where the Name column of the process table takes its text from.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ProcessNameSource {
+    #[default]
+    Comm,
+    Executable,
+    CommandLine,
+}
+
+impl ProcessNameSource {
+    pub const ALL: [Self; 3] = [Self::Comm, Self::Executable, Self::CommandLine];
 }
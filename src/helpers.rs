@@ -22,3 +22,83 @@ pub fn get_bytes(bytes: u64) -> String {
         format!("{} GiB", format_number(bytes as f64 / 1024f64.powf(3.)))
     }
 }
+
+/// Matches a name against a pattern where `*` stands for any run of characters.
+pub fn matches_pattern(pattern: &str, name: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = name.strip_prefix(first) else {
+        return false;
+    };
+
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        // No wildcard, the whole name has to match.
+        return rest.is_empty();
+    };
+
+    for part in middle {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+
+    rest.ends_with(last)
+}
+
+/// Looks up the name of the user running the app in the passwd database.
+pub fn current_username() -> Option<String> {
+    let mut passwd: libc::passwd = unsafe { std::mem::zeroed() };
+    let mut buf = vec![0; 4096];
+    let mut result = std::ptr::null_mut();
+
+    // SAFETY: all pointers refer to live locals, and `buf` outlives the returned entry.
+    let status = unsafe {
+        libc::getpwuid_r(
+            libc::getuid(),
+            &mut passwd,
+            buf.as_mut_ptr(),
+            buf.len(),
+            &mut result,
+        )
+    };
+    if status != 0 || result.is_null() {
+        return None;
+    }
+
+    // SAFETY: on success `pw_name` points to a NUL-terminated string inside `buf`.
+    unsafe { std::ffi::CStr::from_ptr(passwd.pw_name) }
+        .to_str()
+        .ok()
+        .map(str::to_owned)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::matches_pattern;
+
+    #[test]
+    fn pattern_without_wildcard_matches_whole_name() {
+        assert!(matches_pattern("kworker", "kworker"));
+        assert!(!matches_pattern("kworker", "kworker/0:1"));
+        assert!(!matches_pattern("worker", "kworker"));
+    }
+
+    #[test]
+    fn wildcard_matches_any_run_of_characters() {
+        assert!(matches_pattern("kworker*", "kworker/0:1-events"));
+        assert!(matches_pattern("kworker*", "kworker"));
+        assert!(matches_pattern("*d", "systemd"));
+        assert!(matches_pattern("*", "anything"));
+        assert!(matches_pattern("k*/*events", "kworker/0:1-events"));
+        assert!(!matches_pattern("k*/*events", "kworker-events"));
+        assert!(!matches_pattern("*d", "dbus"));
+    }
+
+    #[test]
+    fn middle_parts_must_not_overlap() {
+        assert!(matches_pattern("a*aa*a", "aaaaa"));
+        assert!(!matches_pattern("ab*ba", "aba"));
+    }
+}
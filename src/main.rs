@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: MPL-2.0
 
 mod app;
+mod autostart;
 mod config;
 mod helpers;
 mod i18n;
@@ -16,13 +17,20 @@ fn main() -> cosmic::iced::Result {
     // Enable localizations to be applied.
     i18n::init(&requested_languages);
 
+    let flags = app::Flags::from_args(std::env::args());
+
     // Settings for configuring the application window and iced runtime.
-    let settings = cosmic::app::Settings::default().size_limits(
-        cosmic::iced::Limits::NONE
-            .min_width(360.0)
-            .min_height(180.0),
-    );
+    // Closing the main window is handled by the app so it can keep running in the background.
+    let settings = cosmic::app::Settings::default()
+        .size_limits(
+            cosmic::iced::Limits::NONE
+                .min_width(app::MIN_SIZE.width)
+                .min_height(app::MIN_SIZE.height),
+        )
+        .exit_on_close(false)
+        // Started from the autostart entry, collect data without opening a window.
+        .no_main_window(flags.background());
 
-    // Starts the application's event loop with `()` as the application's flags.
-    cosmic::app::run::<app::AppModel>(settings, ())
+    // Starts the application's event loop, handing off to a running instance if there is one.
+    cosmic::app::run_single_instance::<app::AppModel>(settings, flags)
 }
@@ -1,9 +1,13 @@
+use crate::config::GraphPalette;
 use cosmic::{iced, prelude::*, widget::canvas::*};
 
 #[derive(Clone)]
 pub struct LineGraph {
     // Points (out of 1)
     pub points: Vec<f32>,
+    // Pinned points to compare against (out of 1)
+    pub baseline: Option<Vec<f32>>,
+    pub palette: GraphPalette,
 }
 
 impl Program<crate::app::Message, Theme> for LineGraph {
@@ -18,6 +22,7 @@ impl Program<crate::app::Message, Theme> for LineGraph {
         _cursor: iced::core::mouse::Cursor,
     ) -> Vec<Geometry<Renderer>> {
         let cosmic = theme.cosmic();
+        let color = crate::widget::style::graph_color(self.palette, cosmic);
 
         let bounds = iced::Rectangle::new(
             iced::Point::new(0.0, 0.0),
@@ -54,22 +59,35 @@ impl Program<crate::app::Message, Theme> for LineGraph {
 
         // Draw points
         let bounds = bounds.shrink(1.0);
-        let x_step = bounds.width / (self.points.len() as f32 - 1.0);
 
-        let mut builder = path::Builder::new();
-        let mut current_pos = bounds.position() + iced::Vector::new(0.0, bounds.height);
-        builder.move_to(current_pos);
-        for (index, point) in self.points.iter().enumerate() {
-            let x = index as f32 * x_step;
-            let y = (bounds.y + bounds.height) - point * bounds.height;
-            let control = x - (x_step * 0.5);
-            builder.bezier_curve_to(
-                iced::Point::new(control, current_pos.y),
-                iced::Point::new(control, y),
-                iced::Point::new(x, y),
+        // Draw the pinned baseline as a dashed line underneath the live trace
+        if let Some(baseline) = &self.baseline {
+            let start = iced::Point::new(
+                0.0,
+                (bounds.y + bounds.height)
+                    - baseline.first().copied().unwrap_or_default() * bounds.height,
+            );
+            let mut builder = path::Builder::new();
+            builder.move_to(start);
+            trace(&mut builder, baseline, bounds, start);
+            frame.stroke(
+                &builder.build(),
+                Stroke {
+                    style: Style::Solid(iced::Color { a: 0.6, ..color }),
+                    width: 2.0,
+                    line_dash: LineDash {
+                        segments: &[6.0, 4.0],
+                        offset: 0,
+                    },
+                    ..Default::default()
+                },
             );
-            current_pos = iced::Point::new(x, y);
         }
+
+        let mut builder = path::Builder::new();
+        let start = bounds.position() + iced::Vector::new(0.0, bounds.height);
+        builder.move_to(start);
+        trace(&mut builder, &self.points, bounds, start);
         builder.line_to(iced::Point::new(
             bounds.x + bounds.width,
             bounds.y + bounds.height,
@@ -79,7 +97,7 @@ impl Program<crate::app::Message, Theme> for LineGraph {
         frame.stroke(
             &path,
             Stroke {
-                style: Style::Solid(cosmic.accent_color().into()),
+                style: Style::Solid(color),
                 width: 2.0,
                 ..Default::default()
             },
@@ -87,15 +105,7 @@ impl Program<crate::app::Message, Theme> for LineGraph {
         frame.fill(
             &path,
             Fill {
-                style: Style::Solid(
-                    cosmic
-                        .accent_color()
-                        .apply(|mut color| {
-                            color.alpha = 0.25;
-                            color
-                        })
-                        .into(),
-                ),
+                style: Style::Solid(iced::Color { a: 0.25, ..color }),
                 ..Default::default()
             },
         );
@@ -122,7 +132,7 @@ impl Program<crate::app::Message, Theme> for LineGraph {
         frame.stroke(
             &square.build(),
             Stroke {
-                style: Style::Solid(cosmic.accent_color().into()),
+                style: Style::Solid(color),
                 width: 2.0,
                 ..Default::default()
             },
@@ -131,3 +141,25 @@ impl Program<crate::app::Message, Theme> for LineGraph {
         vec![frame.into_geometry()]
     }
 }
+
+/// Appends a smoothed curve through the points to the path, continuing from `current_pos`.
+fn trace(
+    builder: &mut path::Builder,
+    points: &[f32],
+    bounds: iced::Rectangle,
+    mut current_pos: iced::Point,
+) {
+    let x_step = bounds.width / (points.len() as f32 - 1.0);
+
+    for (index, point) in points.iter().enumerate() {
+        let x = index as f32 * x_step;
+        let y = (bounds.y + bounds.height) - point * bounds.height;
+        let control = x - (x_step * 0.5);
+        builder.bezier_curve_to(
+            iced::Point::new(control, current_pos.y),
+            iced::Point::new(control, y),
+            iced::Point::new(x, y),
+        );
+        current_pos = iced::Point::new(x, y);
+    }
+}
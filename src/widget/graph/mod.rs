@@ -1,2 +1,4 @@
 pub mod line;
+pub mod treemap;
 pub use line::LineGraph;
+pub use treemap::{Treemap, TreemapEntry};
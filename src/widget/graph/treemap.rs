@@ -0,0 +1,239 @@
+use crate::config::GraphPalette;
+use cosmic::{
+    iced::{self, mouse},
+    prelude::*,
+    widget::canvas::*,
+};
+
+#[derive(Clone, Debug)]
+pub struct TreemapEntry {
+    pub pid: u32,
+    pub label: String,
+    pub value: f32,
+}
+
+/// Rectangles sized by value, largest first, in shades of the graph color. Clicking one
+/// shows that process.
+#[derive(Clone)]
+pub struct Treemap {
+    // Entries sorted by value, largest first
+    pub entries: Vec<TreemapEntry>,
+    pub palette: GraphPalette,
+}
+
+impl Program<crate::app::Message, Theme> for Treemap {
+    type State = ();
+
+    fn update(
+        &self,
+        _state: &mut Self::State,
+        event: Event,
+        bounds: iced::Rectangle,
+        cursor: mouse::Cursor,
+    ) -> (event::Status, Option<crate::app::Message>) {
+        let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) = event else {
+            return (event::Status::Ignored, None);
+        };
+        let Some(position) = cursor.position_in(bounds) else {
+            return (event::Status::Ignored, None);
+        };
+
+        let values: Vec<f32> = self.entries.iter().map(|entry| entry.value).collect();
+        let bounds = iced::Rectangle::with_size(bounds.size());
+        squarify(&values, bounds)
+            .iter()
+            .position(|rect| rect.contains(position))
+            .map(|index| {
+                (
+                    event::Status::Captured,
+                    Some(crate::app::Message::ShowProcess(self.entries[index].pid)),
+                )
+            })
+            .unwrap_or((event::Status::Ignored, None))
+    }
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: iced::Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Vec<Geometry<Renderer>> {
+        let cosmic = theme.cosmic();
+        let color = crate::widget::style::graph_color(self.palette, cosmic);
+        let hovered = cursor.position_in(bounds);
+
+        let mut frame = Frame::new(renderer, bounds.size());
+        let values: Vec<f32> = self.entries.iter().map(|entry| entry.value).collect();
+        let rects = squarify(&values, iced::Rectangle::with_size(bounds.size()));
+
+        for (index, (entry, rect)) in self.entries.iter().zip(rects).enumerate() {
+            // Step through five shades of the graph color in layout order, so rectangles
+            // placed next to each other in a row never share one
+            let mut alpha = 0.35 + (index % 5) as f32 * 0.1;
+            if hovered.is_some_and(|position| rect.contains(position)) {
+                alpha = 1.0;
+            }
+
+            let mut builder = path::Builder::new();
+            builder.rounded_rectangle(rect.position(), rect.size(), cosmic.radius_xs().into());
+            let path = builder.build();
+            frame.fill(
+                &path,
+                Fill {
+                    style: Style::Solid(iced::Color { a: alpha, ..color }),
+                    ..Default::default()
+                },
+            );
+            frame.stroke(
+                &path,
+                Stroke {
+                    style: Style::Solid(cosmic.bg_color().into()),
+                    width: 2.0,
+                    ..Default::default()
+                },
+            );
+
+            // Only label rectangles big enough to hold a few characters
+            if rect.width > 48.0 && rect.height > 20.0 {
+                let max_chars = ((rect.width - 8.0) / 7.0) as usize;
+                frame.fill_text(Text {
+                    content: entry.label.chars().take(max_chars).collect(),
+                    position: rect.position() + iced::Vector::new(4.0, 4.0),
+                    color: cosmic.on_bg_color().into(),
+                    size: iced::Pixels(12.0),
+                    ..Default::default()
+                });
+            }
+        }
+
+        vec![frame.into_geometry()]
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Self::State,
+        bounds: iced::Rectangle,
+        cursor: mouse::Cursor,
+    ) -> mouse::Interaction {
+        if cursor.is_over(bounds) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+/// Lays out values, sorted largest first, as rectangles filling the bounds while keeping
+/// their aspect ratios close to square.
+fn squarify(values: &[f32], bounds: iced::Rectangle) -> Vec<iced::Rectangle> {
+    let mut rects = Vec::with_capacity(values.len());
+    let total: f32 = values.iter().sum();
+    if total <= 0.0 {
+        return rects;
+    }
+
+    let scale = bounds.width * bounds.height / total;
+    let areas: Vec<f32> = values.iter().map(|value| value * scale).collect();
+
+    let mut rest = bounds;
+    let mut start = 0;
+    while start < areas.len() {
+        // Grow the row while doing so makes its worst aspect ratio better
+        let side = rest.width.min(rest.height);
+        let mut end = start + 1;
+        while end < areas.len()
+            && worst_ratio(&areas[start..=end], side) <= worst_ratio(&areas[start..end], side)
+        {
+            end += 1;
+        }
+
+        let row = &areas[start..end];
+        let row_area: f32 = row.iter().sum();
+        if rest.width >= rest.height {
+            let width = row_area / rest.height;
+            let mut y = rest.y;
+            for area in row {
+                let height = area / width;
+                rects.push(iced::Rectangle::new(
+                    iced::Point::new(rest.x, y),
+                    iced::Size::new(width, height),
+                ));
+                y += height;
+            }
+            rest.x += width;
+            rest.width -= width;
+        } else {
+            let height = row_area / rest.width;
+            let mut x = rest.x;
+            for area in row {
+                let width = area / height;
+                rects.push(iced::Rectangle::new(
+                    iced::Point::new(x, rest.y),
+                    iced::Size::new(width, height),
+                ));
+                x += width;
+            }
+            rest.y += height;
+            rest.height -= height;
+        }
+
+        start = end;
+    }
+
+    rects
+}
+
+fn worst_ratio(row: &[f32], side: f32) -> f32 {
+    let sum: f32 = row.iter().sum();
+    let max = row.iter().cloned().fold(0.0, f32::max);
+    let min = row.iter().cloned().fold(f32::INFINITY, f32::min);
+    let side = side * side;
+    let sum = sum * sum;
+    (side * max / sum).max(sum / (side * min))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::squarify;
+    use cosmic::iced::{Point, Rectangle, Size};
+
+    fn bounds() -> Rectangle {
+        Rectangle::new(Point::ORIGIN, Size::new(600.0, 400.0))
+    }
+
+    #[test]
+    fn areas_are_proportional_to_values() {
+        let values = [6.0, 6.0, 4.0, 3.0, 2.0, 2.0, 1.0];
+        let rects = squarify(&values, bounds());
+        assert_eq!(rects.len(), values.len());
+
+        let total: f32 = values.iter().sum();
+        for (value, rect) in values.iter().zip(&rects) {
+            let expected = value / total * 600.0 * 400.0;
+            assert!((rect.area() - expected).abs() < 0.5);
+        }
+    }
+
+    #[test]
+    fn rectangles_stay_in_bounds_without_overlapping() {
+        let rects = squarify(&[5.0, 3.0, 3.0, 2.0, 1.0, 1.0], bounds());
+        let inside = bounds().expand(0.01);
+
+        for (index, rect) in rects.iter().enumerate() {
+            assert!(inside.contains(rect.position()));
+            assert!(inside.contains(Point::new(rect.x + rect.width, rect.y + rect.height)));
+            for other in &rects[index + 1..] {
+                let overlap = rect.intersection(other).map_or(0.0, |r| r.area());
+                assert!(overlap < 0.01);
+            }
+        }
+    }
+
+    #[test]
+    fn nothing_to_lay_out() {
+        assert!(squarify(&[], bounds()).is_empty());
+        assert!(squarify(&[0.0, 0.0], bounds()).is_empty());
+    }
+}
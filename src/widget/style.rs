@@ -0,0 +1,16 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! Colors shared by Observatory's custom widgets.
+
+use crate::config::GraphPalette;
+use cosmic::{cosmic_theme, iced::Color};
+
+/// Color used for graph lines, fills and borders in the given palette.
+pub fn graph_color(palette: GraphPalette, cosmic: &cosmic_theme::Theme) -> Color {
+    match palette {
+        GraphPalette::Accent => cosmic.accent_color().into(),
+        GraphPalette::HighContrast => cosmic.on_bg_color().into(),
+        // Okabe-Ito blue, which stays distinct under deuteranopia and protanopia.
+        GraphPalette::ColorblindSafe => Color::from_rgb8(0x00, 0x72, 0xB2),
+    }
+}